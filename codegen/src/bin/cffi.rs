@@ -7,11 +7,11 @@
 //! See Jelal's README for more information.
 // TODO test on environments without rustfmt.
 
-use std::process::Command;
+use std::{collections::HashSet, process::Command};
 
 use codegen::{
     resolve_type::TypeResolver,
-    util::{is_ident, lit_str_expr, name_value_str, write_output},
+    util::{block_doc, is_ident, lit_str_expr, write_output},
     C_FEATURE, FILES_PREFIX, LIB_NAME, OUTPUT,
 };
 use quote::{format_ident, ToTokens};
@@ -47,6 +47,10 @@ fn main() {
     let mut cffi = CFfi::default();
     cffi.visit_file(&expand);
 
+    for (ident, e) in &cffi.errors {
+        eprintln!("skipped `{}`: {}", ident, e);
+    }
+
     write_output(&dest, cffi.generate_content()).unwrap();
     println!("wrote: {:?}", &dest);
 }
@@ -116,9 +120,48 @@ fn module_select<'src, 'idents>(items: &'src mut Vec<Item>, module_path: &'ident
     true
 }
 
+/// The reasons an item cannot be written in C.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum CFfiError {
+    /// A path which is not a single ident.
+    UnacceptablePath(String),
+    /// A type with no C equivalent (like tuples).
+    UnacceptableType(String),
+    /// A parameter which is not an owned ident (or is a receiver).
+    UnacceptableParam(String),
+    /// A type which is skipped itself.
+    SkippedType(String),
+}
+
+impl std::fmt::Display for CFfiError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::UnacceptablePath(v) => write!(
+                f,
+                "path `{}` unsupported (import with `use` and make accessable to other modules \
+                 instead)",
+                v
+            ),
+            Self::UnacceptableType(v) => write!(f, "type `{}` unsupported", v),
+            Self::UnacceptableParam(v) => write!(
+                f,
+                "parameter `{}` unsupported (only owned ident patterns are)",
+                v
+            ),
+            Self::SkippedType(v) => write!(f, "type `{}` is skipped", v),
+        }
+    }
+}
+
+impl std::error::Error for CFfiError {}
+
 #[derive(Default)]
 struct CFfi {
     pub type_resolver: TypeResolver,
+    /// The items skipped (by their ident) and why.
+    pub errors: Vec<(String, CFfiError)>,
+    /// The types skipped so the items using them are skipped too.
+    pub skipped_types: HashSet<String>,
     pub typedefs: String,
     pub structs: String,
     pub statics: String,
@@ -173,6 +216,12 @@ impl CFfi {
         )
     }
 
+    /// Skip a type and the items using it for the given reason.
+    fn skip_type(&mut self, ident: String, e: CFfiError) {
+        self.skipped_types.insert(ident.clone());
+        self.errors.push((ident, e));
+    }
+
     // TODO impl using traits
     /// Only select public items.
     fn is_acceptable_vis(vis: &syn::Visibility) -> bool {
@@ -185,50 +234,37 @@ impl CFfi {
             .is_some_and(|i| i.name.as_ref().is_some_and(|i| i.value() == "C"))
     }
 
-    /// Return the `doc` attribute if available and a literal string.
-    ///
-    /// Has a trailing "\n" if a valid line.
-    ///
-    /// This function only takes the first doc that is a literal string. It's suggested to collapse
-    /// the documents using `expand`-like commands or `collapse-doc` runs or
-    /// [`codegen::util::collapse_docs`].  Since the input comes directly from the `codegen` binary,
-    /// this is already the case.
-    fn doc(attrs: &Vec<syn::Attribute>) -> String {
-        let Some(str_doc) = attrs
-            .iter()
-            .find_map(|i| name_value_str(i, "doc"))
-            .map(|i| i.value())
-        else {
-            return Default::default();
-        };
-        let c_doc = format!(
-            "/**\n{} */\n",
-            str_doc
-                .split('\n')
-                .map(|i| format!(" *{}\n", i))
-                .collect::<String>()
-        );
-        c_doc
-    }
-
     /// Given a type, will resolve it to a C primitive if possible.
     ///
     /// The resulting type will have an extra space for formatting purposes.
-    fn resolve_ctype(ty: &syn::Type) -> String {
+    ///
+    /// `u128` and `i128` are `__uint128_t` and `__int128_t` which are extensions of GCC and Clang
+    /// (not standard C) and may not match the alignment of Rust on all targets.
+    fn resolve_ctype(&self, ty: &syn::Type) -> Result<String, CFfiError> {
         let mut results = match ty {
             syn::Type::Reference(v) => {
                 format!(
                     "{}*{}",
-                    Self::resolve_ctype(&v.elem),
+                    self.resolve_ctype(&v.elem)?,
                     match v.mutability {
                         Some(_) => "",
                         None => "const",
                     }
                 )
             }
-            // // TODO make the length available to C
-            syn::Type::Slice(v) => format!("{}*const", Self::resolve_ctype(&v.elem)),
-            syn::Type::Array(v) => format!("{}*const", Self::resolve_ctype(&v.elem)),
+            syn::Type::Ptr(v) => {
+                format!(
+                    "{}*{}",
+                    self.resolve_ctype(&v.elem)?,
+                    match v.mutability {
+                        Some(_) => "",
+                        None => "const",
+                    }
+                )
+            }
+            // the lengths are given separately (see `visit_item_fn` and `visit_item_static`)
+            syn::Type::Slice(v) => format!("{}*const", self.resolve_ctype(&v.elem)?),
+            syn::Type::Array(v) => format!("{}*const", self.resolve_ctype(&v.elem)?),
             syn::Type::Path(type_path)
                 if type_path.qself.is_none() && type_path.path.require_ident().is_ok() =>
             {
@@ -237,36 +273,45 @@ impl CFfi {
                 match ty_str.as_str() {
                     "bool" => "bool",
                     "char" => "uint32_t",
+                    "c_char" => "char",
+                    "c_int" => "int",
+                    "c_long" => "long",
                     "u8" => "uint8_t",
                     "u16" => "uint16_t",
                     "u32" => "uint32_t",
                     "u64" => "uint64_t",
+                    "u128" => "__uint128_t",
                     "usize" => "uintptr_t",
                     "i8" => "int8_t",
                     "i16" => "int16_t",
                     "i32" => "int32_t",
                     "i64" => "int64_t",
+                    "i128" => "__int128_t",
                     "isize" => "intptr_t",
                     "f32" => "float",
                     "f64" => "double",
+                    v if self.skipped_types.contains(v) => {
+                        return Err(CFfiError::SkippedType(ty_str));
+                    }
                     _ => &ty_str,
                 }
                 .to_owned()
             }
-            syn::Type::Path(type_path) => panic!(
-                "unacceptable path was passed to resolve function \
-                 (import with `use` and make accessable to other modules instead): `{}`",
-                type_path.to_token_stream().to_string()
-            ),
-            _ => panic!(
-                "unacceptable type was passed to resolve function: `{}`",
-                ty.to_token_stream().to_string()
-            ),
+            syn::Type::Path(type_path) => {
+                return Err(CFfiError::UnacceptablePath(
+                    type_path.to_token_stream().to_string(),
+                ));
+            }
+            _ => {
+                return Err(CFfiError::UnacceptableType(
+                    ty.to_token_stream().to_string(),
+                ));
+            }
         };
         if results.chars().last() != Some('*') {
             results.push(' ');
         }
-        results
+        Ok(results)
     }
 }
 
@@ -274,7 +319,19 @@ impl CFfi {
 impl<'a> Visit<'a> for CFfi {
     fn visit_file(&mut self, i: &syn::File) {
         self.type_resolver.visit_file(i);
-        visit_file(self, i);
+        // the items may use the types skipped after them so repeat until no new type is skipped
+        loop {
+            let skipped = self.skipped_types.len();
+            self.errors.clear();
+            self.typedefs.clear();
+            self.structs.clear();
+            self.statics.clear();
+            self.fns.clear();
+            visit_file(self, i);
+            if self.skipped_types.len() == skipped {
+                break;
+            }
+        }
     }
 
     fn visit_item_static(&mut self, i: &'a syn::ItemStatic) {
@@ -315,18 +372,30 @@ impl<'a> Visit<'a> for CFfi {
             return;
         };
 
+        // arrays are declared with their length as they are not pointers
+        let (ty, export_name) = match &*i.ty {
+            syn::Type::Array(v) => (
+                self.resolve_ctype(&v.elem),
+                format!("{}[{}]", export_name, v.len.to_token_stream()),
+            ),
+            ty => (self.resolve_ctype(ty), export_name),
+        };
+        let ty = match ty {
+            Ok(ty) => ty,
+            Err(e) => return self.errors.push((i.ident.to_string(), e)),
+        };
         self.statics.push_str(&format!(
             "{}\
              extern {} {}{};\n\
              \n\
             ",
-            Self::doc(&i.attrs),
+            block_doc(&i.attrs, ""),
             if i.mutability.to_token_stream().is_empty() {
                 "const"
             } else {
                 ""
             },
-            Self::resolve_ctype(&i.ty),
+            ty,
             export_name,
         ));
 
@@ -339,8 +408,8 @@ impl<'a> Visit<'a> for CFfi {
         }
 
         let ret = match &i.sig.output {
-            syn::ReturnType::Default => "void ".to_owned(),
-            syn::ReturnType::Type(_, ty) => Self::resolve_ctype(&ty),
+            syn::ReturnType::Default => Ok("void ".to_owned()),
+            syn::ReturnType::Type(_, ty) => self.resolve_ctype(&ty),
         };
 
         let params = i
@@ -348,32 +417,77 @@ impl<'a> Visit<'a> for CFfi {
             .inputs
             .iter()
             .map(|i| match i {
-                syn::FnArg::Receiver(_) => panic!("method defined as global function"),
+                // a method defined as global function
+                syn::FnArg::Receiver(v) => Err(CFfiError::UnacceptableParam(
+                    v.to_token_stream().to_string(),
+                )),
                 syn::FnArg::Typed(pat_type) => match &*pat_type.pat {
                     syn::Pat::Ident(pat_ident) if pat_ident.by_ref.is_none() => {
                         let ident = pat_ident.ident.to_string();
-                        format!(
-                            "{}{}{}",
-                            match &pat_ident.mutability {
+                        let ident = if ident == "this" { "self" } else { &ident }; // reserved in C
+                        if let syn::Type::Reference(v) = &*pat_type.ty {
+                            let constness = match v.mutability {
                                 Some(_) => "",
                                 None => "const ",
+                            };
+                            match &*v.elem {
+                                // slices are given as a pointer and a length
+                                syn::Type::Slice(slice) => {
+                                    return Ok(format!(
+                                        "{}{}*const {2}, const uintptr_t {2}_len",
+                                        constness,
+                                        self.resolve_ctype(&slice.elem)?,
+                                        ident,
+                                    ));
+                                }
+                                syn::Type::Array(array) => {
+                                    return Ok(format!(
+                                        "{}{}{}[{}]",
+                                        constness,
+                                        self.resolve_ctype(&array.elem)?,
+                                        ident,
+                                        array.len.to_token_stream(),
+                                    ));
+                                }
+                                _ => {}
+                            }
+                        }
+
+                        let ty = self.resolve_ctype(&pat_type.ty)?;
+                        let is_mut_pointer = match &*pat_type.ty {
+                            syn::Type::Reference(v) => v.mutability.is_some(),
+                            syn::Type::Ptr(v) => v.mutability.is_some(),
+                            _ => false,
+                        };
+                        Ok(format!(
+                            "{}{}",
+                            // the pointer is constant not what it points to (written through)
+                            match (&pat_ident.mutability, is_mut_pointer) {
+                                (Some(_), _) => ty,
+                                (None, true) => format!("{}const ", ty),
+                                (None, false) => format!("const {}", ty),
                             },
-                            Self::resolve_ctype(&pat_type.ty),
-                            if ident == "this" { "self" } else { &ident } // `this` is reserved in C
-                        )
+                            ident
+                        ))
                     }
-                    _ => panic!("only owned ident pat types are supported as of now"),
+                    pat => Err(CFfiError::UnacceptableParam(
+                        pat.to_token_stream().to_string(),
+                    )),
                 },
             })
-            .reduce(|acc, i| acc + ", " + &i)
-            .unwrap_or_default();
+            .collect::<Result<Vec<_>, _>>();
+
+        let (ret, params) = match (ret, params) {
+            (Ok(ret), Ok(params)) => (ret, params.join(", ")),
+            (Err(e), _) | (_, Err(e)) => return self.errors.push((i.sig.ident.to_string(), e)),
+        };
 
         self.fns.push_str(&format!(
             "{}\
-             {}{}({});
+             {}{}({});\n\
              \n\
             ",
-            Self::doc(&i.attrs),
+            block_doc(&i.attrs, ""),
             ret,
             i.sig.ident.to_string(),
             params,
@@ -389,14 +503,18 @@ impl<'a> Visit<'a> for CFfi {
             return;
         }
 
+        let ty = match self.resolve_ctype(&i.ty) {
+            Ok(ty) => ty,
+            Err(e) => return self.skip_type(i.ident.to_string(), e),
+        };
         self.typedefs.push_str(&format!(
             "\
              {}\
              typedef {}{};\n\
              \n\
             ",
-            Self::doc(&i.attrs),
-            Self::resolve_ctype(&i.ty),
+            block_doc(&i.attrs, ""),
+            ty,
             i.ident.to_string(),
         ));
 
@@ -409,7 +527,7 @@ impl<'a> Visit<'a> for CFfi {
         }
 
         let ident_str = i.ident.to_string();
-        let doc = Self::doc(&i.attrs);
+        let doc = block_doc(&i.attrs, "");
 
         // There are two cases for a struct, it's either a "dissolvable" type meaning it's like a
         // `transparent` and can be equal to a `repr(int)` in that case adding it as a simple
@@ -441,10 +559,14 @@ impl<'a> Visit<'a> for CFfi {
                         .as_ref()
                         .map(|i| i.to_string())
                         .unwrap_or(format!("m{}", i));
-                    let ty = Self::resolve_ctype(&field.ty);
-                    format!("  {}{};\n", ty, ident)
+                    let ty = self.resolve_ctype(&field.ty)?;
+                    Ok(format!("  {}{};\n", ty, ident))
                 })
-                .collect::<String>();
+                .collect::<Result<String, _>>();
+            let fields = match fields {
+                Ok(fields) => fields,
+                Err(e) => return self.skip_type(ident_str, e),
+            };
             self.structs.push_str(&format!(
                 "\
                  {}\
@@ -459,4 +581,214 @@ impl<'a> Visit<'a> for CFfi {
 
         visit_item_struct(self, i);
     }
+
+    fn visit_item_enum(&mut self, i: &'a syn::ItemEnum) {
+        if !Self::is_acceptable_vis(&i.vis) {
+            return;
+        }
+
+        let ident_str = i.ident.to_string();
+        let prefix = ident_str.to_ascii_uppercase();
+        let variants = i
+            .variants
+            .iter()
+            .map(|variant| {
+                let discriminant = variant
+                    .discriminant
+                    .as_ref()
+                    .map(|(_, expr)| format!(" = {}", expr.to_token_stream()))
+                    .unwrap_or_default();
+                format!(
+                    "  {}_{}{},\n",
+                    prefix,
+                    variant.ident.to_string().to_ascii_uppercase(),
+                    discriminant
+                )
+            })
+            .collect::<String>();
+
+        // C enums are `int` sized so an enum with an integer `repr` is typedef-ed to that integer
+        // and its variants are only the constants (anonymous to not conflict the typedef in C++).
+        let repr = i.attrs.iter().find_map(|attr| {
+            attr.path()
+                .is_ident("repr")
+                .then(|| attr.parse_args::<syn::Type>().ok())
+                .flatten()
+                .filter(|ty| ty.to_token_stream().to_string() != "C")
+        });
+        let definition = match repr.map(|i| self.resolve_ctype(&i)) {
+            Some(Err(e)) => return self.skip_type(ident_str, e),
+            Some(Ok(repr)) => format!(
+                "enum {{\n{variants}}};\ntypedef {repr}{ident};\n",
+                ident = ident_str,
+            ),
+            None => format!("typedef enum {ident_str} {{\n{variants}}} {ident_str};\n"),
+        };
+
+        self.typedefs
+            .push_str(&format!("{}{}\n", block_doc(&i.attrs, ""), definition));
+
+        visit_item_enum(self, i);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_enum_repr_int() {
+        let mut cffi = CFfi::default();
+        cffi.visit_item_enum(&syn::parse_quote! {
+            /// Doc.
+            #[repr(u8)]
+            pub enum Season {
+                Spring = 0,
+                Summer = 1,
+            }
+        });
+        assert_eq!(
+            cffi.typedefs,
+            "/**\n * Doc.\n */\n\
+             enum {\n  SEASON_SPRING = 0,\n  SEASON_SUMMER = 1,\n};\n\
+             typedef uint8_t Season;\n\n"
+        );
+    }
+
+    #[test]
+    fn test_slice_and_array_lengths() {
+        let mut cffi = CFfi::default();
+        cffi.visit_item_fn(&syn::parse_quote! {
+            pub unsafe extern "C" fn f(values: &[u8], counts: &mut [u8], days: &[u8; 7]) {}
+        });
+        assert_eq!(
+            cffi.fns,
+            "void f(const uint8_t *const values, const uintptr_t values_len, \
+             uint8_t *const counts, const uintptr_t counts_len, const uint8_t days[7]);\n\n"
+        );
+
+        cffi.visit_item_static(&syn::parse_quote! {
+            #[unsafe(export_name = "DAYS")]
+            pub static _DAYS: [u8; 7] = DAYS;
+        });
+        assert_eq!(cffi.statics, "extern const uint8_t DAYS[7];\n\n");
+    }
+
+    #[test]
+    fn test_multi_line_doc() {
+        let mut cffi = CFfi::default();
+        cffi.visit_item_fn(&syn::parse_quote! {
+            /// First line.
+            ///
+            /// Third line.
+            pub extern "C" fn f() {}
+        });
+        assert_eq!(
+            cffi.fns,
+            "/**\n * First line.\n *\n * Third line.\n */\nvoid f();\n\n"
+        );
+    }
+
+    #[test]
+    fn test_unsupported_types_skipped() {
+        let mut cffi = CFfi::default();
+        cffi.visit_item_fn(&syn::parse_quote! {
+            pub extern "C" fn tuple(value: (u8, u8)) -> u8 {}
+        });
+        cffi.visit_item_fn(&syn::parse_quote! {
+            pub extern "C" fn path() -> crate::Date {}
+        });
+        cffi.visit_item_struct(&syn::parse_quote! {
+            pub struct Pair {
+                value: (u8, u8),
+            }
+        });
+        cffi.visit_item_fn(&syn::parse_quote! {
+            pub extern "C" fn kept(value: u8) -> u8 {}
+        });
+        assert_eq!(
+            cffi.errors,
+            [
+                (
+                    "tuple".to_owned(),
+                    CFfiError::UnacceptableType("(u8 , u8)".to_owned())
+                ),
+                (
+                    "path".to_owned(),
+                    CFfiError::UnacceptablePath("crate :: Date".to_owned())
+                ),
+                (
+                    "Pair".to_owned(),
+                    CFfiError::UnacceptableType("(u8 , u8)".to_owned())
+                ),
+            ]
+        );
+        assert_eq!(cffi.structs, "");
+        assert_eq!(cffi.fns, "uint8_t kept(const uint8_t value);\n\n");
+    }
+
+    #[test]
+    fn test_dependents_of_skipped_types_skipped() {
+        let mut cffi = CFfi::default();
+        cffi.visit_file(&syn::parse_quote! {
+            pub extern "C" fn first(value: &Outer) -> u8 {}
+            pub struct Outer {
+                pair: Pair,
+            }
+            pub type Alias = Pair;
+            pub struct Pair {
+                value: (u8, u8),
+            }
+            pub extern "C" fn kept(value: u8) -> u8 {}
+        });
+        assert_eq!(
+            cffi.errors,
+            [
+                (
+                    "first".to_owned(),
+                    CFfiError::SkippedType("Outer".to_owned())
+                ),
+                (
+                    "Outer".to_owned(),
+                    CFfiError::SkippedType("Pair".to_owned())
+                ),
+                (
+                    "Alias".to_owned(),
+                    CFfiError::SkippedType("Pair".to_owned())
+                ),
+                (
+                    "Pair".to_owned(),
+                    CFfiError::UnacceptableType("(u8 , u8)".to_owned())
+                ),
+            ]
+        );
+        assert_eq!(cffi.typedefs, "");
+        assert_eq!(cffi.structs, "");
+        assert_eq!(cffi.fns, "uint8_t kept(const uint8_t value);\n\n");
+    }
+
+    #[test]
+    fn test_128_bit_integers() {
+        let mut cffi = CFfi::default();
+        cffi.visit_item_fn(&syn::parse_quote! {
+            pub extern "C" fn f(value: i128) -> u128 {}
+        });
+        assert_eq!(cffi.fns, "__uint128_t f(const __int128_t value);\n\n");
+    }
+
+    #[test]
+    fn test_enum_repr_c() {
+        let mut cffi = CFfi::default();
+        cffi.visit_item_enum(&syn::parse_quote! {
+            #[repr(C)]
+            pub enum Side {
+                Left,
+                Right,
+            }
+        });
+        assert_eq!(
+            cffi.typedefs,
+            "typedef enum Side {\n  SIDE_LEFT,\n  SIDE_RIGHT,\n} Side;\n\n"
+        );
+    }
 }
@@ -3,6 +3,10 @@
 //! The main code lives at "visit" module. There are many limitations and caveats to take into
 //! account before using. Read the docs for that module carefully.
 //!
+//! Takes an optional `--use-namespace=PATH` input which is where the original structs are available
+//! from in the generated code (defaults to `crate`) and an optional `--fn-prefix=PREFIX` which is
+//! the prefix of the global functions in non-C mode (defaults to `jelal_`).
+//!
 //! There are limitations to what this crate is designed to do.
 //! - The list of structs to parse and whitelist is hardcoded with [`IDENTS`].
 //!
@@ -28,13 +32,49 @@ fn parse_file(path: &str) -> Result<Vec<Item>, Box<dyn Error>> {
     Ok(parsed.items)
 }
 
-fn generate_content(items: Vec<Item>) -> TokenStream {
+/// Read the value of the given `--flag=` from the arguments if given.
+fn flag_value(flag: &str) -> Option<String> {
+    std::env::args()
+        .skip(1)
+        .find_map(|i| i.strip_prefix(flag).map(ToOwned::to_owned))
+}
+
+/// Read the `--use-namespace` input as a path or return `crate` if not given.
+fn use_namespace() -> Result<syn::Path, Box<dyn Error>> {
+    const FLAG: &str = "--use-namespace=";
+    let Some(namespace) = flag_value(FLAG) else {
+        return Ok(syn::parse_quote! { crate });
+    };
+    syn::parse_str(&namespace).map_err(|e| {
+        format!("`{FLAG}` expects a path like `my_crate::calendar`, got `{namespace}`: {e}").into()
+    })
+}
+
+/// Read the `--fn-prefix` input or return `{LIB_NAME}_` if not given.
+fn fn_prefix() -> Result<String, Box<dyn Error>> {
+    const FLAG: &str = "--fn-prefix=";
+    let prefix = flag_value(FLAG).unwrap_or_else(|| format!("{}_", LIB_NAME));
+    match syn::parse_str::<syn::Ident>(&format!("{}f", prefix)) {
+        Ok(_) => Ok(prefix),
+        Err(e) => Err(format!(
+            "`{FLAG}` expects the start of an ident like `jelal_`, got `{prefix}`: {e}"
+        )
+        .into()),
+    }
+}
+
+fn generate_content(items: Vec<Item>, use_namespace: syn::Path, fn_prefix: &str) -> TokenStream {
     let mut file = File {
         shebang: Default::default(),
         attrs: Default::default(),
         items,
     };
-    RustFfi::new(IDENTS.iter().map(|i| format_ident!("{}", i)).collect()).visit_file_mut(&mut file);
+    RustFfi::new(
+        IDENTS.iter().map(|i| format_ident!("{}", i)).collect(),
+        use_namespace,
+        fn_prefix,
+    )
+    .visit_file_mut(&mut file);
     let items = file.items;
 
     quote! {
@@ -45,6 +85,8 @@ fn generate_content(items: Vec<Item>) -> TokenStream {
 
         #![allow(deprecated)]
         #![allow(unused_imports)]
+        // slice inputs are transmuted in place which may be inside the output `transmute`
+        #![allow(unused_unsafe)]
 
         #![cfg_attr(feature = #PY_FEATURE, allow(unsafe_op_in_unsafe_fn))]
 
@@ -70,6 +112,8 @@ fn generate_content(items: Vec<Item>) -> TokenStream {
 
 fn main() -> Result<(), Box<dyn Error>> {
     println!("run from the root of this binary's project");
+    let use_namespace = use_namespace()?;
+    let fn_prefix = fn_prefix()?;
 
     let files = FILES
         .into_iter()
@@ -77,7 +121,11 @@ fn main() -> Result<(), Box<dyn Error>> {
         .collect::<Result<Vec<Vec<Item>>, _>>()?;
     println!("read: {:?}", FILES);
 
-    let content = generate_content(files.into_iter().flatten().collect());
+    let content = generate_content(
+        files.into_iter().flatten().collect(),
+        use_namespace,
+        &fn_prefix,
+    );
 
     let path = prefixed_path(OUTPUT);
     write_output(&path, content)?;
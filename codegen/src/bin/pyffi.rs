@@ -0,0 +1,423 @@
+//! Generate Python type stubs (`.pyi`) from the `codegen` output.
+//!
+//! Like `tsffi`, the generated file is read as is since expanding it would also expand (and
+//! remove) the `pyo3` attributes that mark the exported items. Instead, the `cfg` and `cfg_attr`
+//! attributes are evaluated as if only the [`FEATURES`] are enabled (see [`enabled_attrs`]).
+//!
+//! Takes one input which is the filename of the destination stubs.
+//!
+//! See Jelal's README for more information.
+
+use codegen::{
+    resolve_type::TypeResolver,
+    util::{
+        collapse_docs, enabled_attrs, lit_str_expr, name_value_str, prefixed_path, write_output,
+    },
+    LIB_NAME, OUTPUT, PY_FEATURE, STD_FEATURE,
+};
+use quote::ToTokens;
+use syn::{punctuated::Punctuated, visit::*, Item, Meta, Token};
+
+/// The features enabled when building for Python.
+const FEATURES: &[&str] = &[PY_FEATURE, STD_FEATURE, "ffi"];
+
+fn main() {
+    let dest = std::env::args()
+        .nth(1)
+        .expect("give the destination filename as input");
+
+    println!("run from the root of this binary's project");
+
+    let content = std::fs::read_to_string(prefixed_path(OUTPUT)).unwrap();
+    let file = syn::parse_file(&content).unwrap();
+
+    let mut pyffi = PyFfi::default();
+    pyffi.visit_file(&file);
+
+    write_output(&dest, pyffi.generate_content()).unwrap();
+    println!("wrote: {:?}", dest);
+}
+
+/// Return the enabled attributes if the item is enabled and has the given `pyo3` attribute.
+fn pyo3_attrs(attrs: &[syn::Attribute], ident: &str) -> Option<Vec<Meta>> {
+    let attrs = enabled_attrs(attrs, FEATURES)?;
+    attrs
+        .iter()
+        .any(|i| i.path().is_ident(ident))
+        .then_some(attrs)
+}
+
+/// Return the `doc` attributes as a docstring if any.
+///
+/// Has a trailing "\n" if a valid line and every line is prefixed with `indent`.
+fn docstring(attrs: &[syn::Attribute], indent: &str) -> String {
+    let mut attrs = attrs.to_vec();
+    collapse_docs(&mut attrs);
+    let Some(str_doc) = attrs
+        .iter()
+        .find_map(|i| name_value_str(i, "doc"))
+        .map(|i| i.value())
+    else {
+        return Default::default();
+    };
+    format!(
+        "{indent}\"\"\"\n{}{indent}\"\"\"\n",
+        str_doc
+            .split('\n')
+            .map(|i| {
+                // the doc comments are written as `/// line` and keep the space
+                let i = i.strip_prefix(' ').unwrap_or(i);
+                if i.is_empty() {
+                    "\n".to_owned()
+                } else {
+                    format!("{indent}{i}\n")
+                }
+            })
+            .collect::<String>()
+    )
+}
+
+/// Return the body of a `def` which is its docstring if any or `...` otherwise.
+fn def_body(attrs: &[syn::Attribute], indent: &str) -> String {
+    match docstring(attrs, indent) {
+        doc if doc.is_empty() => " ...\n".to_owned(),
+        doc => format!("\n{}", doc),
+    }
+}
+
+/// A class and its members in order.
+#[derive(Default)]
+struct Class {
+    doc: String,
+    members: String,
+}
+
+#[derive(Default)]
+struct PyFfi {
+    pub type_resolver: TypeResolver,
+    /// The idents of the classes in order of appearance (and their declarations).
+    pub classes: Vec<(String, Class)>,
+    pub fns: String,
+}
+
+impl PyFfi {
+    /// Create a final Python stub from the information available.
+    pub fn generate_content(&self) -> String {
+        let classes = self
+            .classes
+            .iter()
+            .map(|(ident, class)| {
+                let body = format!("{}{}", class.doc, class.members);
+                format!(
+                    "class {}:\n{}\n",
+                    ident,
+                    if body.is_empty() { "    ...\n" } else { &body }
+                )
+            })
+            .collect::<String>();
+        format!(
+            "\
+             # Automatically created using `codegen` internal crate.\n\
+             #\n\
+             # Repository: https://crates.io/crate/{}\n\
+             # License: Licensed dually under MIT or Apache-2.0 (see the repository for more)\n\
+             \n\
+             from typing import Any\n\
+             \n\
+             {}\
+             {}\
+            ",
+            LIB_NAME, classes, self.fns,
+        )
+    }
+
+    /// Return the class of this ident and create it if not available.
+    fn class(&mut self, ident: &str) -> &mut Class {
+        let index = match self.classes.iter().position(|(i, _)| i == ident) {
+            Some(index) => index,
+            None => {
+                self.classes.push((ident.to_owned(), Default::default()));
+                self.classes.len() - 1
+            }
+        };
+        &mut self.classes[index].1
+    }
+
+    /// Given a type, will resolve it to a Python type.
+    ///
+    /// Aliases are resolved to their primitives and the unsupported types are `Any`.
+    fn resolve_pytype(&self, ty: &syn::Type) -> String {
+        match ty {
+            syn::Type::Reference(v) => self.resolve_pytype(&v.elem),
+            syn::Type::Paren(v) => self.resolve_pytype(&v.elem),
+            syn::Type::Tuple(v) if v.elems.is_empty() => "None".to_owned(),
+            syn::Type::Slice(syn::TypeSlice { elem, .. })
+            | syn::Type::Array(syn::TypeArray { elem, .. }) => {
+                format!("list[{}]", self.resolve_pytype(elem))
+            }
+            syn::Type::Path(type_path) if type_path.qself.is_none() => {
+                let Some(last) = type_path.path.segments.last() else {
+                    return "Any".to_owned();
+                };
+                if let syn::PathArguments::AngleBracketed(args) = &last.arguments {
+                    return match (last.ident.to_string().as_str(), args.args.first()) {
+                        ("Option", Some(syn::GenericArgument::Type(ty))) => {
+                            format!("{} | None", self.resolve_pytype(ty))
+                        }
+                        _ => "Any".to_owned(),
+                    };
+                }
+
+                let ty = self.type_resolver.resolve_alias(&last.ident.to_string());
+                match ty.as_str() {
+                    "bool" => "bool",
+                    "u8" | "u16" | "u32" | "u64" | "u128" | "usize" | "i8" | "i16" | "i32"
+                    | "i64" | "i128" | "isize" => "int",
+                    "f32" | "f64" => "float",
+                    "char" | "str" | "String" => "str",
+                    _ => return ty,
+                }
+                .to_owned()
+            }
+            _ => "Any".to_owned(),
+        }
+    }
+
+    /// Return the parameters and the return type of a signature.
+    ///
+    /// A receiver is kept as `self` and `Self` is resolved to the given type.
+    fn sig(&self, sig: &syn::Signature, self_ty: Option<&str>) -> (String, String) {
+        let deself = |ty: String| match (ty.as_str(), self_ty) {
+            ("Self", Some(self_ty)) => self_ty.to_owned(),
+            _ => ty,
+        };
+        let params = sig
+            .inputs
+            .iter()
+            .map(|i| match i {
+                syn::FnArg::Receiver(_) => "self".to_owned(),
+                syn::FnArg::Typed(pat_type) => {
+                    let ident = match &*pat_type.pat {
+                        syn::Pat::Ident(pat_ident) => pat_ident.ident.to_string(),
+                        pat => pat.to_token_stream().to_string(),
+                    };
+                    format!("{}: {}", ident, deself(self.resolve_pytype(&pat_type.ty)))
+                }
+            })
+            .collect::<Vec<_>>()
+            .join(", ");
+        let ret = match &sig.output {
+            syn::ReturnType::Default => "None".to_owned(),
+            syn::ReturnType::Type(_, ty) => deself(self.resolve_pytype(ty)),
+        };
+        (params, ret)
+    }
+}
+
+/// Trusts that the input is the output of `codegen` binary.
+impl<'a> Visit<'a> for PyFfi {
+    fn visit_file(&mut self, i: &syn::File) {
+        self.type_resolver.visit_file(i);
+        for item in &i.items {
+            match item {
+                Item::Struct(v) => self.visit_item_struct(v),
+                Item::Enum(v) => self.visit_item_enum(v),
+                Item::Impl(v) => self.visit_item_impl(v),
+                Item::Fn(v) => self.visit_item_fn(v),
+                _ => {}
+            }
+        }
+    }
+
+    fn visit_item_struct(&mut self, i: &'a syn::ItemStruct) {
+        if pyo3_attrs(&i.attrs, "pyclass").is_none() {
+            return;
+        }
+        let doc = docstring(&i.attrs, "    ");
+        self.class(&i.ident.to_string()).doc = doc;
+    }
+
+    fn visit_item_enum(&mut self, i: &'a syn::ItemEnum) {
+        if pyo3_attrs(&i.attrs, "pyclass").is_none() {
+            return;
+        }
+
+        let ident = i.ident.to_string();
+        let variants = i
+            .variants
+            .iter()
+            .map(|variant| {
+                format!(
+                    "    {}: {}\n{}",
+                    variant.ident,
+                    ident,
+                    docstring(&variant.attrs, "    ")
+                )
+            })
+            .collect::<String>();
+        let class = self.class(&ident);
+        class.doc = docstring(&i.attrs, "    ");
+        class.members.push_str(&variants);
+    }
+
+    fn visit_item_impl(&mut self, i: &'a syn::ItemImpl) {
+        if i.trait_.is_some() || pyo3_attrs(&i.attrs, "pymethods").is_none() {
+            return;
+        }
+        let self_ty = i.self_ty.to_token_stream().to_string();
+
+        let mut members = String::new();
+        for item in &i.items {
+            // every method of `pymethods` is exported regardless of its visibility
+            let syn::ImplItem::Fn(f) = item else {
+                continue;
+            };
+            let Some(attrs) = enabled_attrs(&f.attrs, FEATURES) else {
+                continue;
+            };
+
+            let has = |ident: &str| attrs.iter().any(|i| i.path().is_ident(ident));
+            let name = attrs
+                .iter()
+                .filter_map(|meta| match meta {
+                    Meta::List(list) if list.path.is_ident("pyo3") => list
+                        .parse_args_with(Punctuated::<Meta, Token![,]>::parse_terminated)
+                        .ok(),
+                    _ => None,
+                })
+                .flatten()
+                .find_map(|i| match i {
+                    Meta::NameValue(kv) if kv.path.is_ident("name") => {
+                        lit_str_expr(&kv.value).map(|i| i.value())
+                    }
+                    _ => None,
+                })
+                .unwrap_or_else(|| f.sig.ident.to_string());
+
+            let (params, ret) = self.sig(&f.sig, Some(&self_ty));
+            let body = def_body(&f.attrs, "        ");
+            let declaration = if has("new") {
+                let params = if params.is_empty() {
+                    "self".to_owned()
+                } else {
+                    format!("self, {}", params)
+                };
+                format!("    def __init__({}) -> None:{}", params, body)
+            } else {
+                format!(
+                    "{}    def {}({}) -> {}:{}",
+                    if has("staticmethod") {
+                        "    @staticmethod\n"
+                    } else {
+                        ""
+                    },
+                    name,
+                    params,
+                    ret,
+                    body,
+                )
+            };
+            members.push_str(&declaration);
+        }
+
+        self.class(&self_ty).members.push_str(&members);
+    }
+
+    fn visit_item_fn(&mut self, i: &'a syn::ItemFn) {
+        if pyo3_attrs(&i.attrs, "pyfunction").is_none() {
+            return;
+        }
+
+        let (params, ret) = self.sig(&i.sig, None);
+        self.fns.push_str(&format!(
+            "def {}({}) -> {}:{}\n",
+            i.sig.ident,
+            params,
+            ret,
+            def_body(&i.attrs, "    "),
+        ));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stubs() {
+        let file: syn::File = syn::parse_quote! {
+            type IDayDiff = i32;
+
+            #[cfg_attr(feature = "py", pyclass)]
+            pub struct Date {
+                days: IDayDiff,
+            }
+
+            #[cfg_attr(feature = "py", pymethods)]
+            impl Date {
+                pub fn is_leap(&self) -> bool {}
+
+                #[cfg(feature = "py")]
+                #[new]
+                pub fn __py_only_new(days: IDayDiff) -> Date {}
+
+                #[cfg(feature = "c")]
+                pub fn c_only(&self) -> bool {}
+            }
+
+            #[cfg(feature = "py")]
+            #[pymethods]
+            impl Date {
+                #[cfg(feature = "py")]
+                #[staticmethod]
+                #[pyo3(name = "ext_from_ordinal")]
+                pub fn __py_only_ext_from_ordinal(value: Ordinal) -> Self {}
+            }
+
+            #[cfg_attr(feature = "wasm", wasm_bindgen)]
+            impl Date {
+                pub fn wasm_only(&self) -> bool {}
+            }
+
+            /// Add days.
+            #[cfg_attr(feature = "py", pyfunction)]
+            pub fn jelal_date_add_days(this: &Date, days: IDayDiff) -> Date {}
+        };
+        let mut pyffi = PyFfi::default();
+        pyffi.visit_file(&file);
+        let content = pyffi.generate_content();
+        assert!(content.ends_with(
+            "class Date:\n    \
+                 def is_leap(self) -> bool: ...\n    \
+                 def __init__(self, days: int) -> None: ...\n    \
+                 @staticmethod\n    \
+                 def ext_from_ordinal(value: Ordinal) -> Date: ...\n\
+             \n\
+             def jelal_date_add_days(this: Date, days: int) -> Date:\n    \
+                 \"\"\"\n    \
+                 Add days.\n    \
+                 \"\"\"\n\
+             \n"
+        ));
+    }
+
+    #[test]
+    fn test_enum() {
+        let mut pyffi = PyFfi::default();
+        pyffi.visit_item_enum(&syn::parse_quote! {
+            #[cfg_attr(feature = "py", pyclass(eq, eq_int))]
+            pub enum Season {
+                Spring = 0,
+                Summer = 1,
+            }
+        });
+        assert_eq!(
+            pyffi
+                .generate_content()
+                .split_once("from typing import Any\n\n")
+                .unwrap()
+                .1,
+            "class Season:\n    Spring: Season\n    Summer: Season\n\n"
+        );
+    }
+}
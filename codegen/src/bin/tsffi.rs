@@ -0,0 +1,397 @@
+//! Generate TypeScript declarations (`.d.ts`) from the `codegen` output.
+//!
+//! Unlike `cffi`, the generated file is read as is since expanding it would also expand (and
+//! remove) the `wasm_bindgen` attributes that mark the exported items. Instead, the `cfg` and
+//! `cfg_attr` attributes are evaluated as if only the [`FEATURES`] are enabled (see
+//! [`enabled_attrs`]).
+//!
+//! Takes one input which is the filename of the destination declarations.
+//!
+//! See Jelal's README for more information.
+
+use codegen::{
+    resolve_type::TypeResolver,
+    util::{block_doc, enabled_attrs, prefixed_path, write_output},
+    LIB_NAME, OUTPUT, STD_FEATURE, WASM_FEATURE,
+};
+use proc_macro2::TokenStream;
+use quote::ToTokens;
+use syn::{punctuated::Punctuated, visit::*, Item, Meta, Token};
+
+/// The features enabled when building for WASM.
+const FEATURES: &[&str] = &[WASM_FEATURE, STD_FEATURE, "ffi"];
+
+fn main() {
+    let dest = std::env::args()
+        .nth(1)
+        .expect("give the destination filename as input");
+
+    println!("run from the root of this binary's project");
+
+    let content = std::fs::read_to_string(prefixed_path(OUTPUT)).unwrap();
+    let file = syn::parse_file(&content).unwrap();
+
+    let mut tsffi = TsFfi::default();
+    tsffi.visit_file(&file);
+
+    write_output(&dest, tsffi.generate_content()).unwrap();
+    println!("wrote: {:?}", dest);
+}
+
+/// Return the arguments of `wasm_bindgen` if the item is enabled and exported to WASM.
+fn wasm_bindgen(attrs: &[syn::Attribute]) -> Option<TokenStream> {
+    enabled_attrs(attrs, FEATURES)?
+        .into_iter()
+        .find_map(|meta| match meta {
+            Meta::Path(path) if path.is_ident("wasm_bindgen") => Some(TokenStream::new()),
+            Meta::List(list) if list.path.is_ident("wasm_bindgen") => Some(list.tokens),
+            _ => None,
+        })
+}
+
+/// A class and its members in order.
+#[derive(Default)]
+struct Class {
+    doc: String,
+    members: String,
+}
+
+#[derive(Default)]
+struct TsFfi {
+    pub type_resolver: TypeResolver,
+    /// The idents of the classes in order of appearance (and their declarations).
+    pub classes: Vec<(String, Class)>,
+    pub enums: String,
+    pub fns: String,
+}
+
+impl TsFfi {
+    /// Create a final TypeScript source from the information available.
+    pub fn generate_content(&self) -> String {
+        let classes = self
+            .classes
+            .iter()
+            .map(|(ident, class)| {
+                format!(
+                    "{}export class {} {{\n  free(): void;\n{}}}\n\n",
+                    class.doc, ident, class.members
+                )
+            })
+            .collect::<String>();
+        format!(
+            "\
+             /**\n\
+              * Automatically created using `codegen` internal crate.\n\
+              *\n\
+              * @repository https://crates.io/crate/{}\n\
+              * @license Licensed dually under MIT or Apache-2.0 (see the repository for more)\n\
+              */\n\
+              \n\
+              {}\
+              {}\
+              {}\
+            ",
+            LIB_NAME, self.enums, classes, self.fns,
+        )
+    }
+
+    /// Return the class of this ident and create it if not available.
+    fn class(&mut self, ident: &str) -> &mut Class {
+        let index = match self.classes.iter().position(|(i, _)| i == ident) {
+            Some(index) => index,
+            None => {
+                self.classes.push((ident.to_owned(), Default::default()));
+                self.classes.len() - 1
+            }
+        };
+        &mut self.classes[index].1
+    }
+
+    /// Given a type, will resolve it to a TypeScript type.
+    ///
+    /// Aliases are resolved to their primitives and the unsupported types are `any`.
+    fn resolve_tstype(&self, ty: &syn::Type) -> String {
+        match ty {
+            syn::Type::Reference(v) => self.resolve_tstype(&v.elem),
+            syn::Type::Paren(v) => self.resolve_tstype(&v.elem),
+            syn::Type::Tuple(v) if v.elems.is_empty() => "void".to_owned(),
+            syn::Type::Slice(syn::TypeSlice { elem, .. })
+            | syn::Type::Array(syn::TypeArray { elem, .. }) => {
+                let elem = elem.to_token_stream().to_string();
+                let elem = self.type_resolver.resolve_alias(&elem);
+                match elem.as_str() {
+                    "i8" => "Int8Array".to_owned(),
+                    "u8" => "Uint8Array".to_owned(),
+                    "i16" => "Int16Array".to_owned(),
+                    "u16" => "Uint16Array".to_owned(),
+                    "i32" => "Int32Array".to_owned(),
+                    "u32" => "Uint32Array".to_owned(),
+                    "i64" => "BigInt64Array".to_owned(),
+                    "u64" => "BigUint64Array".to_owned(),
+                    "f32" => "Float32Array".to_owned(),
+                    "f64" => "Float64Array".to_owned(),
+                    _ => format!("{}[]", self.resolve_tstype(&syn::parse_str(&elem).unwrap())),
+                }
+            }
+            syn::Type::Path(type_path) if type_path.qself.is_none() => {
+                let Some(last) = type_path.path.segments.last() else {
+                    return "any".to_owned();
+                };
+                if let syn::PathArguments::AngleBracketed(args) = &last.arguments {
+                    return match (last.ident.to_string().as_str(), args.args.first()) {
+                        ("Option", Some(syn::GenericArgument::Type(ty))) => {
+                            format!("{} | undefined", self.resolve_tstype(ty))
+                        }
+                        _ => "any".to_owned(),
+                    };
+                }
+
+                match self
+                    .type_resolver
+                    .resolve_alias(&last.ident.to_string())
+                    .as_str()
+                {
+                    "bool" => "boolean",
+                    "u8" | "u16" | "u32" | "usize" | "i8" | "i16" | "i32" | "isize" | "f32"
+                    | "f64" => "number",
+                    "u64" | "u128" | "i64" | "i128" => "bigint",
+                    "char" | "str" | "String" => "string",
+                    other => return other.to_owned(),
+                }
+                .to_owned()
+            }
+            _ => "any".to_owned(),
+        }
+    }
+
+    /// Return the parameters and the return type of a signature.
+    fn sig(&self, sig: &syn::Signature, self_ty: Option<&str>) -> (String, String) {
+        let deself = |ty: String| match (ty.as_str(), self_ty) {
+            ("Self", Some(self_ty)) => self_ty.to_owned(),
+            _ => ty,
+        };
+        let params = sig
+            .inputs
+            .iter()
+            .filter_map(|i| match i {
+                syn::FnArg::Receiver(_) => None,
+                syn::FnArg::Typed(pat_type) => {
+                    let ident = match &*pat_type.pat {
+                        syn::Pat::Ident(pat_ident) => pat_ident.ident.to_string(),
+                        pat => pat.to_token_stream().to_string(),
+                    };
+                    Some(format!(
+                        "{}: {}",
+                        // `this` is reserved for the type of `this` in TypeScript
+                        if ident == "this" { "self" } else { &ident },
+                        deself(self.resolve_tstype(&pat_type.ty)),
+                    ))
+                }
+            })
+            .collect::<Vec<_>>()
+            .join(", ");
+        let ret = match &sig.output {
+            syn::ReturnType::Default => "void".to_owned(),
+            syn::ReturnType::Type(_, ty) => deself(self.resolve_tstype(ty)),
+        };
+        (params, ret)
+    }
+}
+
+/// Trusts that the input is the output of `codegen` binary.
+impl<'a> Visit<'a> for TsFfi {
+    fn visit_file(&mut self, i: &syn::File) {
+        self.type_resolver.visit_file(i);
+        for item in &i.items {
+            match item {
+                Item::Struct(v) => self.visit_item_struct(v),
+                Item::Enum(v) => self.visit_item_enum(v),
+                Item::Impl(v) => self.visit_item_impl(v),
+                Item::Fn(v) => self.visit_item_fn(v),
+                _ => {}
+            }
+        }
+    }
+
+    fn visit_item_struct(&mut self, i: &'a syn::ItemStruct) {
+        if wasm_bindgen(&i.attrs).is_none() {
+            return;
+        }
+        let doc = block_doc(&i.attrs, "");
+        self.class(&i.ident.to_string()).doc = doc;
+    }
+
+    fn visit_item_enum(&mut self, i: &'a syn::ItemEnum) {
+        if wasm_bindgen(&i.attrs).is_none() {
+            return;
+        }
+
+        let variants = i
+            .variants
+            .iter()
+            .map(|variant| {
+                let discriminant = variant
+                    .discriminant
+                    .as_ref()
+                    .map(|(_, expr)| format!(" = {}", expr.to_token_stream()))
+                    .unwrap_or_default();
+                format!(
+                    "{}  {}{},\n",
+                    block_doc(&variant.attrs, "  "),
+                    variant.ident,
+                    discriminant
+                )
+            })
+            .collect::<String>();
+        self.enums.push_str(&format!(
+            "{}export enum {} {{\n{}}}\n\n",
+            block_doc(&i.attrs, ""),
+            i.ident,
+            variants
+        ));
+    }
+
+    fn visit_item_impl(&mut self, i: &'a syn::ItemImpl) {
+        if i.trait_.is_some() || wasm_bindgen(&i.attrs).is_none() {
+            return;
+        }
+        let self_ty = i.self_ty.to_token_stream().to_string();
+
+        let mut members = String::new();
+        for item in &i.items {
+            let syn::ImplItem::Fn(f) = item else {
+                continue;
+            };
+            if !matches!(f.vis, syn::Visibility::Public(_)) {
+                continue;
+            }
+            let Some(args) = enabled_attrs(&f.attrs, FEATURES) else {
+                continue;
+            };
+
+            // read `constructor` and `js_name = ...` of the method's own `wasm_bindgen`
+            let args = args
+                .into_iter()
+                .filter_map(|meta| match meta {
+                    Meta::List(list) if list.path.is_ident("wasm_bindgen") => list
+                        .parse_args_with(Punctuated::<Meta, Token![,]>::parse_terminated)
+                        .ok(),
+                    _ => None,
+                })
+                .flatten()
+                .collect::<Vec<_>>();
+            let is_constructor = args.iter().any(|i| i.path().is_ident("constructor"));
+            let js_name = args.iter().find_map(|i| match i {
+                Meta::NameValue(kv) if kv.path.is_ident("js_name") => {
+                    Some(kv.value.to_token_stream().to_string())
+                }
+                _ => None,
+            });
+
+            let (params, ret) = self.sig(&f.sig, Some(&self_ty));
+            let declaration = if is_constructor {
+                format!("constructor({});", params)
+            } else {
+                format!(
+                    "{}{}({}): {};",
+                    if f.sig.receiver().is_some() {
+                        ""
+                    } else {
+                        "static "
+                    },
+                    js_name.unwrap_or_else(|| f.sig.ident.to_string()),
+                    params,
+                    ret,
+                )
+            };
+            members.push_str(&format!("{}  {}\n", block_doc(&f.attrs, "  "), declaration));
+        }
+
+        self.class(&self_ty).members.push_str(&members);
+    }
+
+    fn visit_item_fn(&mut self, i: &'a syn::ItemFn) {
+        if !matches!(i.vis, syn::Visibility::Public(_)) || wasm_bindgen(&i.attrs).is_none() {
+            return;
+        }
+
+        let (params, ret) = self.sig(&i.sig, None);
+        self.fns.push_str(&format!(
+            "{}export function {}({}): {};\n\n",
+            block_doc(&i.attrs, ""),
+            i.sig.ident,
+            params,
+            ret,
+        ));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_declarations() {
+        let file: syn::File = syn::parse_quote! {
+            type IDayDiff = i32;
+
+            /// A date.
+            #[cfg_attr(feature = "wasm", wasm_bindgen)]
+            pub struct Date {
+                days: IDayDiff,
+            }
+
+            #[cfg_attr(feature = "wasm", wasm_bindgen)]
+            impl Date {
+                #[cfg_attr(feature = "wasm", wasm_bindgen(constructor))]
+                pub fn new(days: IDayDiff) -> Date {}
+
+                pub fn is_leap(&self) -> bool {}
+
+                #[cfg_attr(feature = "wasm", wasm_bindgen(js_name = toString))]
+                pub fn ext_to_string(&self) -> ::std::string::String {}
+            }
+
+            #[cfg_attr(feature = "py", pymethods)]
+            impl Date {
+                pub fn py_only(&self) -> bool {}
+            }
+
+            #[cfg_attr(feature = "wasm", wasm_bindgen)]
+            pub fn jelal_date_add_days(this: &Date, days: IDayDiff) -> Date {}
+
+            #[cfg(feature = "c")]
+            #[cfg_attr(feature = "wasm", wasm_bindgen)]
+            pub fn jelal_date_c_only(this: &Date) {}
+        };
+        let mut tsffi = TsFfi::default();
+        tsffi.visit_file(&file);
+        let content = tsffi.generate_content();
+        assert!(content.ends_with(
+            "/**\n * A date.\n */\n\
+             export class Date {\n  \
+               free(): void;\n  \
+               constructor(days: number);\n  \
+               is_leap(): boolean;\n  \
+               toString(): string;\n\
+             }\n\n\
+             export function jelal_date_add_days(self: Date, days: number): Date;\n\n"
+        ));
+    }
+
+    #[test]
+    fn test_enum() {
+        let mut tsffi = TsFfi::default();
+        tsffi.visit_item_enum(&syn::parse_quote! {
+            #[cfg_attr(feature = "wasm", wasm_bindgen)]
+            pub enum Season {
+                Spring = 0,
+                Summer = 1,
+            }
+        });
+        assert_eq!(
+            tsffi.enums,
+            "export enum Season {\n  Spring = 0,\n  Summer = 1,\n}\n\n"
+        );
+    }
+}
@@ -12,10 +12,12 @@ pub const C_FEATURE: &str = "c";
 pub const WASM_FEATURE: &str = "wasm";
 
 /// Match the idents defined here.
-pub const IDENTS: &[&str] = &["Date", "Month", "MonthDay", "Ordinal", "Year"];
+pub const IDENTS: &[&str] = &[
+    "Date", "Month", "MonthDay", "Ordinal", "Season", "Weekday", "Year",
+];
 
 /// Inside these files.
-pub const FILES: [&str; 2] = ["lib.rs", "primitive.rs"];
+pub const FILES: [&str; 4] = ["lib.rs", "primitive.rs", "season.rs", "week.rs"];
 
 /// Indicates the Rust output of the files.
 ///
@@ -3,7 +3,7 @@
 use std::collections::HashMap;
 
 use quote::ToTokens;
-use syn::{visit::*, Ident, Type};
+use syn::{punctuated::Punctuated, visit::*, Ident, Token, Type};
 
 #[derive(Clone, Debug)]
 pub enum ReprInt {
@@ -83,13 +83,17 @@ impl Into<Ident> for ReprInt {
 
 /// Resolves types of a module to their primitives.
 ///
-/// As of now, just resolves a single field `struct` and/or type aliases.
+/// As of now, just resolves a single field `repr(transparent)` struct and/or type aliases. The
+/// `repr(C)` structs are only tracked to tell if their layout is primitive (see
+/// [`Self::is_layout_primitive`]).
 #[derive(Debug, Default, Clone)]
 pub struct TypeResolver {
     /// Values that are essentially equal.
     pub aliases: HashMap<String, String>,
     /// Rest of the types that may be convertable to each other.
     pub from_into_map: HashMap<String, String>,
+    /// Field types of the `repr(C)` structs in order.
+    pub layouts: HashMap<String, Vec<String>>,
 }
 
 impl TypeResolver {
@@ -110,6 +114,41 @@ impl TypeResolver {
         // if an alias is valid, just return that alias instead of converting to keep readablity
         ReprInt::try_from_str(alias).map(|_| alias_k)
     }
+
+    /// Follow the type aliases of `k` to the last one (or itself if not an alias).
+    pub fn resolve_alias(&self, k: &str) -> String {
+        match self.aliases.get(k) {
+            Some(alias) => self.resolve_alias(alias),
+            None => k.to_owned(),
+        }
+    }
+
+    /// Is this a `repr(C)` struct whose fields are all primitives or primitive layout structs.
+    ///
+    /// Such a struct can be reinterpreted (`transmute`d) as a C struct of its primitives instead of
+    /// being converted field by field.
+    pub fn is_layout_primitive(&self, k: &str) -> bool {
+        self.layouts.get(k).is_some_and(|fields| {
+            fields.iter().all(|i| {
+                ReprInt::try_from_str(i).is_some()
+                    || self.repr_alias(i).is_some()
+                    || self.is_layout_primitive(i)
+            })
+        })
+    }
+}
+
+/// Return the idents given to the `repr` attributes (like `C` and `transparent`).
+fn reprs(attrs: &[syn::Attribute]) -> Vec<Ident> {
+    attrs
+        .iter()
+        .filter(|i| i.path().is_ident("repr"))
+        .filter_map(|i| {
+            i.parse_args_with(Punctuated::<Ident, Token![,]>::parse_terminated)
+                .ok()
+        })
+        .flatten()
+        .collect()
 }
 
 impl<'ast> Visit<'ast> for TypeResolver {
@@ -122,9 +161,23 @@ impl<'ast> Visit<'ast> for TypeResolver {
         visit_item_type(self, i);
     }
 
+    /// Register the struct if it has the same layout as its only field (`repr(transparent)`) or
+    /// its layout if `repr(C)`.
     fn visit_item_struct(&mut self, i: &'ast syn::ItemStruct) {
+        let reprs = reprs(&i.attrs);
         let ident = i.ident.to_string();
 
+        if reprs == ["C"] {
+            let fields = i.fields.iter();
+            let fields = fields.map(|i| i.ty.to_token_stream().to_string());
+            self.layouts.insert(ident, fields.collect());
+            visit_item_struct(self, i);
+            return;
+        }
+        if reprs != ["transparent"] {
+            return;
+        }
+
         let mut fields = i.fields.iter();
         let ty = fields
             .next()
@@ -139,3 +192,65 @@ impl<'ast> Visit<'ast> for TypeResolver {
         visit_item_struct(self, i);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_only_transparent_structs_dissolve() {
+        let file: syn::File = syn::parse_quote! {
+            pub type IYear = i32;
+
+            #[repr(transparent)]
+            pub struct Year(IYear);
+
+            pub struct Loose(IYear);
+        };
+        let mut resolver = TypeResolver::default();
+        resolver.visit_file(&file);
+        assert_eq!(resolver.repr_alias("Year"), Some("IYear"));
+        assert_eq!(resolver.repr_alias("Loose"), None);
+    }
+
+    #[test]
+    fn test_is_layout_primitive() {
+        let file: syn::File = syn::parse_quote! {
+            pub type UMonth = u8;
+            pub type UMonthDay = u8;
+
+            #[repr(transparent)]
+            pub struct Month(UMonth);
+
+            #[repr(C)]
+            pub struct MonthDay {
+                month: Month,
+                day: UMonthDay,
+            }
+
+            #[repr(C)]
+            pub struct Date {
+                year: i32,
+                month_day: MonthDay,
+            }
+
+            pub struct RustMonthDay {
+                month: Month,
+                day: UMonthDay,
+            }
+
+            #[repr(C)]
+            pub struct Named {
+                month_day: MonthDay,
+                name: String,
+            }
+        };
+        let mut resolver = TypeResolver::default();
+        resolver.visit_file(&file);
+        assert!(resolver.is_layout_primitive("MonthDay"));
+        assert!(resolver.is_layout_primitive("Date"));
+        assert!(!resolver.is_layout_primitive("RustMonthDay"));
+        assert!(!resolver.is_layout_primitive("Named"));
+        assert!(!resolver.is_layout_primitive("Month"));
+    }
+}
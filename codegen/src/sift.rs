@@ -1,10 +1,18 @@
 //! [`Sift`] and filter the unsupported code with this visit.
 
 use quote::ToTokens;
-use syn::{visit_mut::*, Attribute, Ident, ImplItem, Item, Signature, Token};
+use syn::{
+    parse_quote, punctuated::Punctuated, visit_mut::*, Attribute, Ident, ImplItem, Item, ItemEnum,
+    ItemStruct, Signature, Token,
+};
 
 use crate::util::{as_ident, is_generics_empty, is_ident, is_simple_type, remove_empty_items};
 
+/// The inherent methods that are const-context definitions of the whitelisted traits' methods.
+///
+/// These are dropped since the traits are exported already and would clash with them.
+const TRAIT_METHODS: [&str; 1] = ["eq"];
+
 /// Remove unacceptable code unfit for FFI.
 ///
 /// See [`VisitMut`] methods below for how the selection is made. Each method (as in its docs),
@@ -66,6 +74,42 @@ impl Sift {
         self.structs_whitelist.contains(&ident) && is_generics_empty(&generics)
     }
 
+    /// Accept enumerations if the ident is in list, not generic and all variants have no fields.
+    ///
+    /// Fieldless enums are C-like and can be represented as integers cross FFI.
+    pub fn is_acceptable_enum(&self, item: &ItemEnum) -> bool {
+        self.is_acceptable_struct(&item.ident, &item.generics)
+            && item
+                .variants
+                .iter()
+                .all(|i| matches!(i.fields, syn::Fields::Unit))
+    }
+
+    /// Return an empty trait implementation for each whitelisted trait derived on this struct.
+    ///
+    /// This lets the derived traits be processed the same as the written implementations.
+    pub fn derived_trait_impls(&self, item: &ItemStruct) -> Vec<Item> {
+        if !self.is_acceptable_struct(&item.ident, &item.generics) {
+            return vec![];
+        }
+
+        let ident = &item.ident;
+        item.attrs
+            .iter()
+            .filter(|i| i.path().is_ident("derive"))
+            .filter_map(|i| {
+                i.parse_args_with(Punctuated::<syn::Path, Token![,]>::parse_terminated)
+                    .ok()
+            })
+            .flatten()
+            .map(|path| -> syn::ItemImpl {
+                parse_quote! { impl #path for #ident {} }
+            })
+            .filter(|i| self.is_trait_in_whitelist(&i.trait_).is_some())
+            .map(Item::Impl)
+            .collect()
+    }
+
     /// Accept the signature only if all inputs are ident pattern & not generic.
     ///
     /// Enforcing ident patterns (i.e. `var: ty`) helps parsing, changing and validating the inputs
@@ -94,6 +138,7 @@ impl VisitMut for Sift {
     /// Except the items described below and under the conditions specified, the rest are dropped:
     /// - [`Item::Type`] and [`Item::Const`] are unconditionally selected.
     /// - [`Item::Struct`] is only acceptable if [`Self::is_acceptable_struct`] accepts.
+    /// - [`Item::Enum`] is only acceptable if [`Self::is_acceptable_enum`] accepts.
     /// - [`Item::Impl`] is only acceptable if its struct is acceptable and its type is an `Ident`.
     ///   Trait implementations are only allowed if [`Self::is_acceptable_trait`] passes, again,
     ///   only if its type is an `Ident`.  Except the [`ImplItem`] described below and under the
@@ -101,8 +146,23 @@ impl VisitMut for Sift {
     ///   - [`ImplItem::Type`] and [`ImplItem::Const`] are unconditionally selected.
     ///   - [`ImplItem::Fn`] is only acceptable if its signature is acceptable (see
     ///     [`Self::is_acceptable_sig`]).
+    ///   - [`ImplItem::Fn`] is dropped if in [`TRAIT_METHODS`].
     ///   - Every item from an accepted trait implementation.
+    ///
+    /// Before all, whitelisted derived traits are added as items (see
+    /// [`Self::derived_trait_impls`]).
     fn visit_file_mut(&mut self, i: &mut syn::File) {
+        let derived = i
+            .items
+            .iter()
+            .filter_map(|i| match i {
+                Item::Struct(v) => Some(self.derived_trait_impls(v)),
+                _ => None,
+            })
+            .flatten()
+            .collect::<Vec<_>>();
+        i.items.extend(derived);
+
         // TODO split to this and Sift::empty_items
         i.items.retain_mut(|i| match i {
             Item::Type(_) => true,
@@ -114,7 +174,10 @@ impl VisitMut for Sift {
                         v.items.retain(|i| match i {
                             ImplItem::Const(_) => true,
                             ImplItem::Type(_) => true,
-                            ImplItem::Fn(v) => Self::is_acceptable_sig(&v.sig),
+                            ImplItem::Fn(v) => {
+                                Self::is_acceptable_sig(&v.sig)
+                                    && !TRAIT_METHODS.iter().any(|i| v.sig.ident == i)
+                            }
                             _ => false,
                         });
                         self.is_acceptable_struct(&ident, &v.generics)
@@ -126,6 +189,7 @@ impl VisitMut for Sift {
                 }
             }
             Item::Struct(v) => self.is_acceptable_struct(&v.ident, &v.generics),
+            Item::Enum(v) => self.is_acceptable_enum(v),
             _ => false,
         });
         remove_empty_items(&mut i.items);
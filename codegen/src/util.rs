@@ -5,7 +5,9 @@ use std::io::Write;
 use quote::ToTokens;
 use syn::{
     parse::{Parse, Parser},
-    parse_quote, Ident, Item,
+    parse_quote,
+    punctuated::Punctuated,
+    Ident, Item, Meta, Token,
 };
 
 use crate::FILES_PREFIX;
@@ -134,3 +136,77 @@ pub fn collapse_docs(attrs: &mut Vec<syn::Attribute>) {
         current_index += 1;
     }
 }
+
+/// Return the `doc` attributes as a `/** */` block (both for C and JSDoc) if any.
+///
+/// Has a trailing "\n" if a valid line and every line is prefixed with `indent`.
+///
+/// The documents are collapsed first (see [`collapse_docs`]) and only the first literal string
+/// doc of them is taken.
+pub fn block_doc(attrs: &[syn::Attribute], indent: &str) -> String {
+    let mut attrs = attrs.to_vec();
+    collapse_docs(&mut attrs);
+    let Some(str_doc) = attrs
+        .iter()
+        .find_map(|i| name_value_str(i, "doc"))
+        .map(|i| i.value())
+    else {
+        return Default::default();
+    };
+    format!(
+        "{indent}/**\n{}{indent} */\n",
+        str_doc
+            .split('\n')
+            .map(|i| format!("{indent} *{i}\n"))
+            .collect::<String>()
+    )
+}
+
+/// Evaluate a `cfg` predicate assuming only the given features are enabled.
+///
+/// Only `feature = "..."`, `all`, `any` and `not` are supported and the rest are false.
+pub fn is_cfg_enabled(meta: &Meta, features: &[&str]) -> bool {
+    let nested = |list: &syn::MetaList| {
+        list.parse_args_with(Punctuated::<Meta, Token![,]>::parse_terminated)
+            .unwrap_or_default()
+    };
+    let is_enabled = |i: &Meta| is_cfg_enabled(i, features);
+    match meta {
+        Meta::NameValue(kv) if kv.path.is_ident("feature") => {
+            lit_str_expr(&kv.value).is_some_and(|i| features.contains(&&*i.value()))
+        }
+        Meta::List(list) if list.path.is_ident("all") => nested(list).iter().all(is_enabled),
+        Meta::List(list) if list.path.is_ident("any") => nested(list).iter().any(is_enabled),
+        Meta::List(list) if list.path.is_ident("not") => !nested(list).iter().all(is_enabled),
+        _ => false,
+    }
+}
+
+/// Return the enabled attributes (`cfg_attr`s resolved) or none if a `cfg` is not enabled.
+///
+/// See [`is_cfg_enabled`] for how the predicates are evaluated.
+pub fn enabled_attrs(attrs: &[syn::Attribute], features: &[&str]) -> Option<Vec<Meta>> {
+    let mut enabled = vec![];
+    for attr in attrs {
+        let Meta::List(list) = &attr.meta else {
+            enabled.push(attr.meta.clone());
+            continue;
+        };
+        if list.path.is_ident("cfg") {
+            if !is_cfg_enabled(&list.parse_args().ok()?, features) {
+                return None;
+            }
+        } else if list.path.is_ident("cfg_attr") {
+            let args = list
+                .parse_args_with(Punctuated::<Meta, Token![,]>::parse_terminated)
+                .ok()?;
+            let mut args = args.into_iter();
+            if args.next().is_some_and(|i| is_cfg_enabled(&i, features)) {
+                enabled.extend(args);
+            }
+        } else {
+            enabled.push(attr.meta.clone());
+        }
+    }
+    Some(enabled)
+}
@@ -10,13 +10,10 @@
 //! given a file.
 //!
 //! Limitations of [`RustFfi`]:
-//! - [`RustFfi::use_namespace`] is hardcoded to `crate` which in effect implies that all the
-//!   original structs must be available from `use crate::*;` (see more in [`RustFfi::parent`]).
+//! - [`RustFfi::use_namespace`] is given on creation (`crate` for `jelal`) which in effect implies
+//!   that all the original structs must be available from `use {namespace}::*;` (see more in
+//!   [`RustFfi::parent`]).
 //! - All the items must have [`Ident`] as their idents, type or path (for previous limitations).
-//! - No type is allowed to have a string match of `Self` (case-sensitive) in any ident segment
-//!   ([`RustFfi::deself`]).
-//! - No function or method signiture can have a parameter name ending in `mut`
-//!   ([`RustFfi::pat_type_to_usage`]).
 //! - All the types and fields must be `transmute` compatible as it is used on everything in the
 //!   output.
 //! - All the fields must be public and have `Into` and `From` implemented between the field type
@@ -27,16 +24,19 @@
 //! Ignored items:
 //! - Only "mod style" paths (no generics) are allowed as outputs of items in an `impl` block
 //!   ([`RustFfi::visit_impl_item_mut`]).
-//! - All enums
+//! - Enums with fields (see [`Sift::is_acceptable_enum`])
 //! - All functions
 //! - Methods with mutable reference to primitive integers
-//! - Traits that are not whitelisted
+//! - Traits that are not whitelisted (derived or implemented)
 //!
 //! Changes to items:
 //! - All global functions in C mode will be `no_mangle` and `extern "C"`.
 //! - All functions and methods will be non-const (common `wasm` limitation).
-//! - All functions for non-C mode have a common prefix.
+//! - All functions for non-C mode have a common prefix ([`RustFfi::fn_prefix`]).
 //! - Primitive referenced inputs will be converted to owned.
+//! - Slice inputs are given as a pointer and a length (`{name}_len`) in C.
+//! - Mutable referenced structs (and `&mut self`) are converted to copies which are written back
+//!   to the given reference after the call.
 //! - All inputs will be replaced by their simpler equivalent if available (for example structs with
 //!   one field will be replaced and at boundaries be converted using `Into` and `From` or
 //!   `transmute`).
@@ -72,7 +72,7 @@ use crate::{
     resolve_type::TypeResolver,
     sift::Sift,
     util::{as_ident, collapse_docs, lit_str_expr, remove_empty_items},
-    C_FEATURE, LIB_NAME, PY_FEATURE, WASM_FEATURE,
+    C_FEATURE, LIB_NAME, PY_FEATURE, STD_FEATURE, WASM_FEATURE,
 };
 
 /// Creates `ImplTraitWhitelist`
@@ -113,11 +113,7 @@ macro_rules! impl_trait_whitelist {
     };
 }
 
-// TODO add Display and bind it to __str__ and such methods in different languages
-// TODO add Eq
-// TODO add Ord to comparison methods for different languages
-// TODO make sift keep `derive` inputs and this struct to parse them
-impl_trait_whitelist!(From, Ord);
+impl_trait_whitelist!(From, Ord, Display, Eq, Hash);
 
 /// Ignore lifetime and parse the input as anything with a reference prefix.
 fn parse_and_mut(
@@ -129,12 +125,57 @@ fn parse_and_mut(
     Ok((and, mutability, input.parse::<TokenStream>()?))
 }
 
+/// Replace the `Self` types with the given type (see [`RustFfi::deself_with`]).
+struct Deself<'a>(&'a Type);
+
+impl VisitMut for Deself<'_> {
+    fn visit_type_mut(&mut self, i: &mut Type) {
+        if let Type::Path(type_path) = i {
+            let is_self = type_path.qself.is_none()
+                && type_path
+                    .path
+                    .segments
+                    .first()
+                    .is_some_and(|i| i.ident == "Self" && i.arguments.is_none());
+            match self.0 {
+                _ if !is_self => {}
+                replacement if type_path.path.segments.len() == 1 => {
+                    *i = replacement.clone();
+                    return;
+                }
+                // `Self::Item` to `Replacement::Item`
+                Type::Path(replacement) if replacement.qself.is_none() => {
+                    let rest = type_path.path.segments.iter().skip(1).cloned();
+                    type_path.path.segments = replacement
+                        .path
+                        .segments
+                        .iter()
+                        .cloned()
+                        .chain(rest)
+                        .collect();
+                }
+                _ => {}
+            }
+        }
+
+        visit_type_mut(self, i);
+    }
+}
+
 /// Create an FFI compatible Rust code for `WASM`, `Py`, and `C` features.
 pub struct RustFfi {
     /// A cache for newly created items before the end of the visit.
     added_items: Vec<Item>,
     /// A cache for the function that introduces the module for `pyo3` before the end of the visit.
     pymodule: Option<ItemFn>,
+    /// A cache for `pymethods` that must be in one block per type before the end of the visit.
+    ///
+    /// Comparison methods (like `__eq__` and `__lt__`) share one slot and cannot be split.
+    py_slots: Vec<syn::ItemImpl>,
+    /// The idents of the accepted enums (filled before visiting the items).
+    ///
+    /// Enums are passed by value cross FFI since WASM does not accept references to them.
+    enums: Vec<Ident>,
     /// The type resolver that [`Self::dissolve`] and its related functions (recognize aliases).
     type_resolver: TypeResolver,
     /// The sift that runs on this file before and after being parsed (discards unsupported items).
@@ -143,15 +184,59 @@ pub struct RustFfi {
     ///
     /// This is used mostly in [`Self::parent`]. The imposed limitation is described there.
     use_namespace: TokenStream,
+    /// The prefix of the global functions in non-C mode (like `jelal_` for `jelal_date_weekday`).
+    fn_prefix: String,
     /// Holds the last processed item's ident (the struct or `impl` block ident).
     processing_item: Ident,
 }
 
 impl RustFfi {
+    /// Replace the slice inputs with a pointer and a `{name}_len` input for C.
+    ///
+    /// Returns the statements which recreate the slices from them (makes the function `unsafe`).
+    fn slices_to_raw_parts(sig: &mut Signature) -> Vec<syn::Stmt> {
+        let mut stmts = vec![];
+        let mut inputs = Punctuated::new();
+        for input in std::mem::take(&mut sig.inputs) {
+            let FnArg::Typed(pat_type) = &input else {
+                inputs.push(input);
+                continue;
+            };
+            let (Type::Reference(reference), syn::Pat::Ident(pat_ident)) =
+                (&*pat_type.ty, &*pat_type.pat)
+            else {
+                inputs.push(input);
+                continue;
+            };
+            let Type::Slice(slice) = &*reference.elem else {
+                inputs.push(input);
+                continue;
+            };
+
+            let ident = &pat_ident.ident;
+            let len = format_ident!("{}_len", ident);
+            let elem = &slice.elem;
+            if reference.mutability.is_some() {
+                inputs.push(parse_quote! { #ident: *mut #elem });
+                stmts.push(parse_quote! {
+                    let #ident = unsafe { ::core::slice::from_raw_parts_mut(#ident, #len) };
+                });
+            } else {
+                inputs.push(parse_quote! { #ident: *const #elem });
+                stmts.push(parse_quote! {
+                    let #ident = unsafe { ::core::slice::from_raw_parts(#ident, #len) };
+                });
+            }
+            inputs.push(parse_quote! { #len: usize });
+        }
+        sig.inputs = inputs;
+        stmts
+    }
+
     /// Create standalone functions from this method and add them to the list (no visit).
     fn push_method_fns(&mut self, method: &syn::ImplItemFn) {
         let mut sig = method.sig.clone();
-        let (args, conversions) = self.inputs_to_args_stmts(&mut sig, false);
+        let (args, conversions, writebacks) = self.inputs_to_args_stmts(&mut sig, false);
 
         sig.ident = format_ident!(
             "{}_{}",
@@ -169,12 +254,12 @@ impl RustFfi {
             attrs: method.attrs.clone(),
             vis: method.vis.clone(),
             sig,
-            block: parse_quote! {
-                {
-                    #(#conversions)*
-                    #self_ty::#ident(#args).into() // no transmute allowed, maybe dissolved
-                }
-            },
+            block: Box::new(Self::call_block(
+                conversions,
+                parse_quote! { #self_ty::#ident(#args) },
+                writebacks,
+                |result| parse_quote! { #result.into() }, // no transmute allowed, maybe dissolved
+            )),
         };
 
         {
@@ -182,6 +267,11 @@ impl RustFfi {
             let mut fn_item = fn_item.clone();
             fn_item.sig.ident = format_ident!("{}", fn_item.sig.ident);
             fn_item.sig.abi = parse_quote! { extern "C" };
+            let slices = Self::slices_to_raw_parts(&mut fn_item.sig);
+            if !slices.is_empty() {
+                fn_item.sig.unsafety = Some(Default::default());
+                fn_item.block.stmts.splice(0..0, slices);
+            }
             // multiple configs does not hurt even if cfg(c) is already added
             fn_item
                 .attrs
@@ -202,7 +292,7 @@ impl RustFfi {
             // the method also exists. Then there is also #138762 with "C" ABI in and how it
             // changes when used in wasm32-unknown-unknown.  So the decision was to disable the
             // compatibility.
-            fn_item.sig.ident = format_ident!("_{}", fn_item.sig.ident);
+            fn_item.sig.ident = format_ident!("{}{}", self.fn_prefix, fn_item.sig.ident);
             fn_item.attrs.append(&mut parse_quote! {
                 #[cfg_attr(feature = #PY_FEATURE, pyfunction)]
                 #[cfg_attr(feature = #WASM_FEATURE, wasm_bindgen)]
@@ -241,14 +331,42 @@ impl RustFfi {
         pymodule.block.stmts.insert(0, stmt);
     }
 
+    /// Add the methods to the shared `pymethods` of [`Self::processing_item`] (see [`Self::py_slots`]).
+    fn py_slots_push(&mut self, mut items: Vec<syn::ImplItem>) {
+        let ident = &self.processing_item;
+        let index = match self
+            .py_slots
+            .iter()
+            .position(|i| as_ident(&i.self_ty).is_some_and(|i| &i == ident))
+        {
+            Some(index) => index,
+            None => {
+                self.py_slots.push(parse_quote! {
+                    #[cfg(feature = #PY_FEATURE)]
+                    #[pymethods]
+                    impl #ident {}
+                });
+                self.py_slots.len() - 1
+            }
+        };
+        self.py_slots[index].items.append(&mut items);
+    }
+
     /// Create a new instance.
-    pub fn new(structs_whitelist: Vec<Ident>) -> Self {
+    ///
+    /// The `use_namespace` is where the original structs are available from (see
+    /// [`Self::use_namespace`]) and `fn_prefix` is prepended to the global functions of non-C
+    /// mode (see [`Self::fn_prefix`]).
+    pub fn new(structs_whitelist: Vec<Ident>, use_namespace: syn::Path, fn_prefix: &str) -> Self {
         Self {
             type_resolver: Default::default(),
             added_items: Default::default(),
             pymodule: Default::default(),
+            py_slots: Default::default(),
+            enums: Default::default(),
             processing_item: format_ident!("_placeholder_"),
-            use_namespace: quote! { crate }, // TODO read from args
+            use_namespace: use_namespace.into_token_stream(),
+            fn_prefix: fn_prefix.to_owned(),
             sift: Sift {
                 structs_whitelist,
                 impl_trait_whitelist: ImplTraitWhitelist::as_path_vec(),
@@ -273,12 +391,13 @@ impl RustFfi {
     }
 
     /// Replace `Self` in the given `ty` with the given `replacement`.
+    ///
+    /// Only the `Self` types and the `Self` prefix of the paths (like `Self::Item`) are replaced,
+    /// so the names containing `Self` (like `SelfishThing`) are kept.
     fn deself_with(ty: &Type, replacement: &Type) -> Type {
-        let deselfed = ty
-            .to_token_stream()
-            .to_string()
-            .replace("Self", &replacement.to_token_stream().to_string());
-        syn::parse_str(&deselfed).expect("expected a type with no `Self` in its name")
+        let mut ty = ty.clone();
+        Deself(replacement).visit_type_mut(&mut ty);
+        ty
     }
 
     /// Do dissolve and deself in succession.
@@ -287,10 +406,7 @@ impl RustFfi {
         Self::deself_with(ty, &this)
     }
 
-    /// Replace `Self` with this type.
-    ///
-    /// TODO HACK this is now just a simple string replacement for `Self` which is error prone for
-    /// types that have `Self` in their name like `ThisSelfWrapperStruct`.
+    /// Replace `Self` with this type and dissolve it if possible (see [`Self::deself_with`]).
     fn deself_dissolve(&self, ty: &Type) -> Type {
         let this = self.processing_item.to_string();
         let this_dissolved = self.dissolve(&this).unwrap_or(&this);
@@ -305,6 +421,11 @@ impl RustFfi {
         syn::parse_str(&deselfed).expect("expected a type with no `Self` in its name")
     }
 
+    /// Check if the given type is one of the accepted enums.
+    fn is_enum(&self, ty: &Type) -> bool {
+        as_ident(ty).is_some_and(|i| self.enums.contains(&i))
+    }
+
     /// Return the primitive FFI equivalent for this type (if available).
     fn dissolve<'a>(&'a self, s: &'a str) -> Option<&'a str> {
         self.type_resolver.repr_alias(&s)
@@ -316,14 +437,23 @@ impl RustFfi {
 
     /// Assuming pattern is from [`syn::FnArg::Typed`], convert it to an expression (hopefully).
     ///
-    /// TODO current method makes a string replacement of `mut ` hence any parameter that ends with
-    /// `mut` will cause problem.
+    /// For ident patterns (see [`Sift::is_acceptable_sig`]) only the ident is used so `mut` and
+    /// `ref` are dropped.
     fn pat_type_to_usage(&self, pat_type: &syn::PatType) -> TokenStream {
-        let (_, _, usage) = parse_and_mut
-            .parse2(pat_type.pat.to_token_stream())
-            .unwrap();
+        let usage = match &*pat_type.pat {
+            syn::Pat::Ident(pat_ident) => pat_ident.ident.to_token_stream(),
+            pat => pat.to_token_stream(),
+        };
         let (and, mut mutability, ty) =
             parse_and_mut.parse2(pat_type.ty.to_token_stream()).unwrap();
+
+        // slices and arrays are not `Into`-able so the whole is transmuted (see the limitations)
+        if matches!(
+            Type::parse.parse2(ty.clone()),
+            Ok(Type::Slice(_) | Type::Array(_))
+        ) {
+            return quote! { unsafe { ::core::mem::transmute(#usage) } };
+        }
         let mut clone = and.map(|_| quote! { .clone() });
 
         // if it is a primtive, remove the pointer stuff for simpler FFI usage
@@ -371,8 +501,8 @@ impl RustFfi {
     ///
     /// This primarily checks if the value is a mutable integer pointer or not.  Since other than
     /// objects, no `RefAbi` is defined for integer pointers.  The visitor implemented will convert
-    /// normal immutable pointers to owned instances. However, since `&mut` is not lightly defined
-    /// and also tuple returns are not easily done cross FFI, these functions rejected.
+    /// normal immutable pointers to owned instances and write the mutable struct pointers back.
+    /// However, since tuple returns are not easily done cross FFI, the integer ones are rejected.
     ///
     /// Also see [`Sift::is_acceptable_sig`]. The functionality there cannot check for types since
     /// no `TypeResolver` is utilized in that struct.
@@ -389,11 +519,18 @@ impl RustFfi {
     /// If returns Err, a mutable reference to a primitive is used in inputs hence not usable in
     /// FFI. Check inputs and do not pass to this function if [`Self::is_acceptable_input`] returns
     /// false for any.
+    ///
+    /// Returns the arguments, the statements to run before the call and the ones to run after it
+    /// (writing the mutable references back).
     fn inputs_to_args_stmts(
         &self,
         sig: &mut Signature,
         is_method: bool,
-    ) -> (Punctuated<syn::Expr, Token![,]>, Vec<syn::Stmt>) {
+    ) -> (
+        Punctuated<syn::Expr, Token![,]>,
+        Vec<syn::Stmt>,
+        Vec<syn::Stmt>,
+    ) {
         // convert self to this for not-methods
         if let Some(first) = sig.inputs.first_mut() {
             match first {
@@ -407,22 +544,30 @@ impl RustFfi {
 
         let mut argv = Punctuated::new();
         let mut stmts = vec![];
+        let mut writebacks = vec![];
 
         let parent = self.parent();
         // gather usage and usage-convertor statement for self
         if let Some(receiver) = sig.receiver() {
-            let (prefix, postfix) = match &receiver.reference {
-                Some(_) => {
-                    let mutability = &receiver.mutability;
-                    (quote! { & #mutability }, quote! { .clone() })
-                }
-                None => Default::default(),
-            };
             stmts.push(parse_quote! { let this = self; });
             // this provides "breathing room" for other functions to just rely
             // on `this` instead of "self" whenver receiver was available.
-            stmts.push(parse_quote! { let this: #prefix #parent = #prefix this #postfix .into(); });
-            argv.push(parse_quote! { this });
+            match (&receiver.reference, &receiver.mutability) {
+                (Some(_), Some(_)) => {
+                    // converted values are copies so write the changes back to the original
+                    stmts.push(parse_quote! { let mut __this: #parent = this.clone().into(); });
+                    writebacks.push(parse_quote! { *this = __this.into(); });
+                    argv.push(parse_quote! { &mut __this });
+                }
+                (Some(_), None) => {
+                    stmts.push(parse_quote! { let this: &#parent = &this.clone().into(); });
+                    argv.push(parse_quote! { this });
+                }
+                (None, _) => {
+                    stmts.push(parse_quote! { let this: #parent = this.into(); });
+                    argv.push(parse_quote! { this });
+                }
+            }
         }
 
         // gather usage and usage-convertor statement for inputs (not self)
@@ -460,12 +605,50 @@ impl RustFfi {
                 }
 
                 argv.push(parse_quote! { &#pat });
+            } else if matches!(deselfed_owned, Type::Slice(_) | Type::Array(_)) {
+                argv.push(parse_quote! { #usage });
+            } else if and.is_some() && mut_.is_none() && self.is_enum(&deselfed_owned) {
+                // pass by value
+                pat_type.ty = Box::new(deselfed_owned);
+                let usage = self.pat_type_to_usage(&pat_type);
+                argv.push(parse_quote! { &#usage });
+            } else if let (Some(_), Some(_), syn::Pat::Ident(pat_ident)) =
+                (and, mut_, &*pat_type.pat)
+            {
+                // converted values are copies so write the changes back to the original
+                let pat = &pat_ident.ident;
+                let copy = format_ident!("__{}", pat);
+                stmts.push(parse_quote! { let mut #copy = #pat.clone().into(); });
+                writebacks.push(parse_quote! { *#pat = #copy.into(); });
+                argv.push(parse_quote! { &mut #copy });
             } else {
                 argv.push(parse_quote! { #usage });
             }
         }
 
-        (argv, stmts)
+        (argv, stmts, writebacks)
+    }
+
+    /// Wrap a call in a block that runs the conversions before and the writebacks after it.
+    fn call_block(
+        conversions: Vec<syn::Stmt>,
+        call: syn::Expr,
+        writebacks: Vec<syn::Stmt>,
+        wrap: impl Fn(syn::Expr) -> syn::Expr,
+    ) -> syn::Block {
+        if writebacks.is_empty() {
+            let result = wrap(call);
+            return parse_quote! { { #(#conversions)* #result } };
+        }
+        let result = wrap(parse_quote! { result });
+        parse_quote! {
+            {
+                #(#conversions)*
+                let result = #call;
+                #(#writebacks)*
+                #result
+            }
+        }
     }
 
     /// Assuming a whitelisted trait is the given `impl`, process it.
@@ -595,13 +778,149 @@ impl RustFfi {
                         });
 
                         self.added_items.push(Item::Impl(impl_trait));
+                        self.push_comparisons();
                     }
                 }
             }
+            // The body is not needed since the parent's `Display` is used.
+            ImplTraitWhitelist::Display => {
+                let ident = &self.processing_item;
+                let parent = self.parent();
+                let c_ident = format_ident!("{}_to_string", ident.to_string().to_ascii_lowercase());
+                let repr = format!("{}({{}})", ident);
+
+                self.added_items.push(parse_quote! {
+                    #[doc = " FFI version of a `Display` trait implementation\n\n Write the text and a null \
+                              byte to `buf` (`len` bytes at most) and return the length of the text \
+                              (without null) even if it did not fit (`snprintf` like).\n\n # Safety\n \
+                              `buf` must be null or valid for writing `len` bytes."]
+                    #[cfg(feature = #C_FEATURE)]
+                    #[unsafe(no_mangle)]
+                    pub unsafe extern "C" fn #c_ident(
+                        this: &#ident,
+                        buf: *mut c_char,
+                        len: usize,
+                    ) -> usize {
+                        unsafe { write_display(&#parent::from(this.clone()), buf, len) }
+                    }
+                });
+                self.added_items.push(parse_quote! {
+                    #[cfg(feature = #PY_FEATURE)]
+                    #[pymethods]
+                    impl #ident {
+                        #[pyo3(name = "__str__")]
+                        fn __py_only_ext_to_string(&self) -> ::std::string::String {
+                            ::std::string::ToString::to_string(&#parent::from(self.clone()))
+                        }
+
+                        #[pyo3(name = "__repr__")]
+                        fn __py_only_ext_repr(&self) -> ::std::string::String {
+                            ::std::format!(#repr, #parent::from(self.clone()))
+                        }
+                    }
+                });
+                self.added_items.push(parse_quote! {
+                    #[cfg(feature = #STD_FEATURE)]
+                    #[cfg_attr(feature = #WASM_FEATURE, wasm_bindgen)]
+                    impl #ident {
+                        #[doc = " FFI version of a `Display` trait implementation"]
+                        #[cfg_attr(feature = #WASM_FEATURE, wasm_bindgen(js_name = toString))]
+                        pub fn ext_to_string(&self) -> ::std::string::String {
+                            ::std::string::ToString::to_string(&#parent::from(self.clone()))
+                        }
+                    }
+                });
+            }
+            // Mostly derived (see `Sift::derived_trait_impls`) so there is no body to process.
+            ImplTraitWhitelist::Eq => {
+                let ident = &self.processing_item;
+                let parent = self.parent();
+                let c_ident = format_ident!("{}_eq", ident.to_string().to_ascii_lowercase());
+
+                self.added_items.push(parse_quote! {
+                    #[doc = " FFI version of an `Eq` trait implementation"]
+                    #[cfg(feature = #C_FEATURE)]
+                    #[unsafe(no_mangle)]
+                    pub extern "C" fn #c_ident(this: &#ident, other: &#ident) -> bool {
+                        #parent::from(this.clone()) == #parent::from(other.clone())
+                    }
+                });
+                let py_eq = parse_quote! {
+                    #[pyo3(name = "__eq__")]
+                    fn __py_only_ext_eq(&self, other: &Self) -> bool {
+                        #parent::from(self.clone()) == #parent::from(other.clone())
+                    }
+                };
+                self.added_items.push(parse_quote! {
+                    #[cfg_attr(feature = #WASM_FEATURE, wasm_bindgen)]
+                    impl #ident {
+                        #[doc = " FFI version of an `Eq` trait implementation"]
+                        #[cfg_attr(feature = #WASM_FEATURE, wasm_bindgen(js_name = equals))]
+                        pub fn ext_eq(&self, other: &Self) -> bool {
+                            #parent::from(self.clone()) == #parent::from(other.clone())
+                        }
+                    }
+                });
+                self.py_slots_push(vec![py_eq]);
+            }
+            // Python only since there is no hashing in the other languages.
+            ImplTraitWhitelist::Hash => {
+                let parent = self.parent();
+                self.py_slots_push(vec![parse_quote! {
+                    #[pyo3(name = "__hash__")]
+                    fn __py_only_ext_hash(&self) -> u64 {
+                        let mut hasher = ::std::hash::DefaultHasher::new();
+                        ::core::hash::Hash::hash(&#parent::from(self.clone()), &mut hasher);
+                        ::core::hash::Hasher::finish(&hasher)
+                    }
+                }]);
+            }
             _ => {}
         }
     }
 
+    /// Add the comparison operators of the language built on top of the `ext_cmp` method.
+    fn push_comparisons(&mut self) {
+        let ident = &self.processing_item;
+        let (names, ops): (Vec<_>, Vec<TokenStream>) = [
+            ("lt", quote! { < }),
+            ("le", quote! { <= }),
+            ("gt", quote! { > }),
+            ("ge", quote! { >= }),
+        ]
+        .into_iter()
+        .unzip();
+        let py_names = names.iter().map(|i| format!("__{}__", i));
+        let py_idents = names.iter().map(|i| format_ident!("__py_only_ext_{}", i));
+        let idents = names
+            .iter()
+            .map(|i| format_ident!("ext_{}", i))
+            .collect::<Vec<_>>();
+
+        let py: syn::ItemImpl = parse_quote! {
+            impl #ident {
+                #(
+                    #[pyo3(name = #py_names)]
+                    fn #py_idents(&self, other: &Self) -> bool {
+                        self.ext_cmp(other) #ops 0
+                    }
+                )*
+            }
+        };
+        self.added_items.push(parse_quote! {
+            #[cfg_attr(feature = #WASM_FEATURE, wasm_bindgen)]
+            impl #ident {
+                #(
+                    #[doc = " FFI version of an `Ord` comparison operator"]
+                    pub fn #idents(&self, other: &Self) -> bool {
+                        self.ext_cmp(other) #ops 0
+                    }
+                )*
+            }
+        });
+        self.py_slots_push(py.items);
+    }
+
     /// Given a valid `deprecated` attribute, returns a string explaining its situation.
     ///
     /// If not a valid deprecated attribute, returns None.
@@ -654,6 +973,14 @@ impl VisitMut for RustFfi {
     fn visit_file_mut(&mut self, i: &mut File) {
         self.sift.visit_file_mut(i);
         self.type_resolver.visit_file(i);
+        self.enums = i
+            .items
+            .iter()
+            .filter_map(|i| match i {
+                Item::Enum(v) => Some(v.ident.clone()),
+                _ => None,
+            })
+            .collect();
 
         visit_file_mut(self, i);
         remove_empty_items(&mut i.items);
@@ -662,6 +989,9 @@ impl VisitMut for RustFfi {
             i.items.push(Item::Fn(pymodule));
         }
 
+        let py_slots = std::mem::take(&mut self.py_slots);
+        i.items.extend(py_slots.into_iter().map(Item::Impl));
+
         let mut added_items = std::mem::take(&mut self.added_items);
         remove_empty_items(&mut added_items);
         i.items.append(&mut added_items);
@@ -768,11 +1098,55 @@ impl VisitMut for RustFfi {
         }
     }
 
+    fn visit_item_enum_mut(&mut self, i: &mut syn::ItemEnum) {
+        self.processing_item = i.ident.clone();
+
+        // keep the original repr if any since outputs are `transmute`d
+        if !i.attrs.iter().any(|i| i.path().is_ident("repr")) {
+            i.attrs.push(parse_quote! { #[repr(C)] });
+        }
+
+        i.attrs.append(&mut parse_quote! {
+            #[cfg_attr(feature = #WASM_FEATURE, wasm_bindgen)]
+            #[cfg_attr(feature = #PY_FEATURE, pyclass(eq, eq_int))]
+            #[derive(Clone, Copy, PartialEq, Eq)]
+        });
+
+        visit_item_enum_mut(self, i);
+
+        self.pymodule_push(&i.ident, &i.attrs, false);
+
+        let parent = self.parent();
+        let ident = &i.ident;
+        let variants = i.variants.iter().map(|i| &i.ident).collect::<Vec<_>>();
+        self.added_items.push(Item::Impl(parse_quote! {
+            impl From<#ident> for #parent {
+                fn from(value: #ident) -> Self {
+                    match value {
+                        #(#ident::#variants => Self::#variants,)*
+                    }
+                }
+            }
+        }));
+        self.added_items.push(Item::Impl(parse_quote! {
+            impl From<#parent> for #ident {
+                fn from(value: #parent) -> Self {
+                    match value {
+                        #(#parent::#variants => Self::#variants,)*
+                    }
+                }
+            }
+        }));
+    }
+
     fn visit_item_impl_mut(&mut self, i: &mut syn::ItemImpl) {
         let Some(ident) = as_ident(&i.self_ty) else {
             return;
             // unsifted non-ident `impl.self_ty`: should not happen but still
         };
+        // WASM does not support methods on enums, the global functions are used instead
+        let wasm_bindgen: Option<syn::Attribute> = (!self.enums.contains(&ident))
+            .then(|| parse_quote! { #[cfg_attr(feature = #WASM_FEATURE, wasm_bindgen)] });
         self.processing_item = ident;
 
         // if it's a trait item, do it like this, and else follow the normal procedure
@@ -855,9 +1229,7 @@ impl VisitMut for RustFfi {
             ..i.clone()
         };
         let mut py = non_py.clone();
-        non_py.attrs.push(parse_quote! {
-            #[cfg_attr(feature = #WASM_FEATURE, wasm_bindgen)]
-        });
+        non_py.attrs.extend(wasm_bindgen.clone());
         // there is this lack of feature in pyo3 that doesn't allow conditional (behind cfg_attr)
         // `pymethods` to have inner attributes like `staticmethod` so a `cfg` style duplication is
         // required
@@ -903,10 +1275,9 @@ impl VisitMut for RustFfi {
         self.added_items.push(Item::Impl(py));
 
         // rest
-        i.attrs.append(&mut parse_quote! {
-            #[cfg_attr(feature = #PY_FEATURE, pymethods)]
-            #[cfg_attr(feature = #WASM_FEATURE, wasm_bindgen)]
-        });
+        i.attrs
+            .push(parse_quote! { #[cfg_attr(feature = #PY_FEATURE, pymethods)] });
+        i.attrs.extend(wasm_bindgen);
     }
 
     fn visit_impl_item_const_mut(&mut self, i: &mut syn::ImplItemConst) {
@@ -985,7 +1356,7 @@ impl VisitMut for RustFfi {
     }
 
     fn visit_impl_item_fn_mut(&mut self, i: &mut syn::ImplItemFn) {
-        let (args, conversions) = self.inputs_to_args_stmts(&mut i.sig, true);
+        let (args, conversions, writebacks) = self.inputs_to_args_stmts(&mut i.sig, true);
 
         // WASM does not accept any `const fn`
         // ??? will this matter for FFI? should this be behind cfg flags?
@@ -995,7 +1366,7 @@ impl VisitMut for RustFfi {
         if let syn::ReturnType::Type(_, ty) = &mut i.sig.output {
             // No need to dissolve since this ruins the functionality of chain method calling in
             // methods. Hence the deself only
-            *ty = Box::new(self.deself(ty));
+            **ty = self.deself(ty);
         }
 
         let parent = self.parent();
@@ -1003,16 +1374,16 @@ impl VisitMut for RustFfi {
 
         // the methods declared here are trusted so if the results is invalid, that's on author.
         // Hence the transmute
-        i.block = parse_quote! {
-            {
-                #(#conversions)*
-                unsafe { ::core::mem::transmute(#parent::#ident(#args)) }
-            }
-        };
+        i.block = Self::call_block(
+            conversions,
+            parse_quote! { #parent::#ident(#args) },
+            writebacks,
+            |result| parse_quote! { unsafe { ::core::mem::transmute(#result) } },
+        );
 
         visit_impl_item_fn_mut(self, i);
 
-        self.push_method_fns(&i);
+        self.push_method_fns(i);
     }
 
     fn visit_field_mut(&mut self, i: &mut syn::Field) {
@@ -1020,3 +1391,234 @@ impl VisitMut for RustFfi {
         visit_field_mut(self, i);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_deself_keeps_substrings() {
+        let ty: Type = parse_quote! { (Self, &SelfishThing, Option<Self>, Self::Item) };
+        let replacement: Type = parse_quote! { Date };
+        assert_eq!(
+            RustFfi::deself_with(&ty, &replacement)
+                .to_token_stream()
+                .to_string(),
+            quote! { (Date, &SelfishThing, Option<Date>, Date::Item) }.to_string()
+        );
+    }
+
+    #[test]
+    fn test_inputs_named_like_mut() {
+        let ffi = RustFfi::new(vec![], parse_quote! { crate }, "jelal_");
+        let mut sig: Signature = parse_quote! {
+            fn f(mut_count: u8, mut count: u8, commit: &Thing, format: &mut Thing)
+        };
+        let (args, _, _) = ffi.inputs_to_args_stmts(&mut sig, false);
+        assert_eq!(
+            args.to_token_stream().to_string(),
+            quote! {
+                mut_count.into(),
+                count.into(),
+                &commit.clone().into(),
+                &mut __format
+            }
+            .to_string()
+        );
+    }
+
+    #[test]
+    fn test_inputs_mut_written_back() {
+        let mut ffi = RustFfi::new(vec![], parse_quote! { crate }, "jelal_");
+        ffi.processing_item = format_ident!("Thing");
+        let mut sig: Signature = parse_quote! { fn f(&mut self, format: &mut Thing) };
+        let (args, stmts, writebacks) = ffi.inputs_to_args_stmts(&mut sig, true);
+        assert_eq!(
+            args.to_token_stream().to_string(),
+            quote! { &mut __this, &mut __format }.to_string()
+        );
+        assert_eq!(
+            quote! { #(#stmts)* }.to_string(),
+            quote! {
+                let this = self;
+                let mut __this: crate::Thing = this.clone().into();
+                let mut __format = format.clone().into();
+            }
+            .to_string()
+        );
+        assert_eq!(
+            quote! { #(#writebacks)* }.to_string(),
+            quote! {
+                *this = __this.into();
+                *format = __format.into();
+            }
+            .to_string()
+        );
+    }
+
+    #[test]
+    fn test_deself_selfish_struct() {
+        let mut file: File = parse_quote! {
+            pub struct SelfishThing {
+                pub value: u8,
+            }
+
+            impl SelfishThing {
+                pub const fn new(value: u8) -> Self {
+                    Self { value }
+                }
+
+                pub const fn with(&self, other: &Self) -> SelfishThing {
+                    Self::new(other.value)
+                }
+            }
+        };
+        RustFfi::new(
+            vec![format_ident!("SelfishThing")],
+            parse_quote! { crate },
+            "jelal_",
+        )
+        .visit_file_mut(&mut file);
+        let output = file.to_token_stream().to_string();
+        assert!(output.contains("fn selfishthing_with"));
+        assert!(!output.contains("SelfishThingishThing"));
+    }
+
+    #[test]
+    fn test_fn_prefix() {
+        let mut file: File = parse_quote! {
+            pub struct Thing {
+                pub value: u8,
+            }
+
+            impl Thing {
+                pub const fn double(&self) -> u8 {
+                    self.value * 2
+                }
+            }
+        };
+        RustFfi::new(vec![format_ident!("Thing")], parse_quote! { crate }, "lib_")
+            .visit_file_mut(&mut file);
+        let output = file.to_token_stream().to_string();
+        // C functions are not prefixed
+        assert!(output.contains("extern \"C\" fn thing_double"));
+        assert!(output.contains("pub fn lib_thing_double"));
+        assert!(output.contains("wrap_pyfunction ! (lib_thing_double"));
+        assert!(!output.contains("fn _thing_double"));
+    }
+
+    #[test]
+    fn test_inherent_trait_methods_dropped() {
+        let mut file: File = parse_quote! {
+            #[derive(PartialEq, Eq)]
+            pub struct Thing {
+                pub value: u8,
+            }
+
+            impl Thing {
+                pub const fn eq(&self, other: &Self) -> bool {
+                    self.value == other.value
+                }
+            }
+        };
+        RustFfi::new(
+            vec![format_ident!("Thing")],
+            parse_quote! { crate },
+            "jelal_",
+        )
+        .visit_file_mut(&mut file);
+        let output = file.to_token_stream().to_string();
+        assert_eq!(output.matches("extern \"C\" fn thing_eq").count(), 1);
+        assert!(output.contains("fn ext_eq"));
+    }
+
+    #[test]
+    fn test_slices_to_raw_parts() {
+        let mut sig: Signature = parse_quote! {
+            fn f(this: &Thing, values: &[Thing], counts: &mut [u8], day: u8)
+        };
+        let stmts = RustFfi::slices_to_raw_parts(&mut sig);
+        assert_eq!(
+            sig.inputs.to_token_stream().to_string(),
+            quote! {
+                this: &Thing,
+                values: *const Thing,
+                values_len: usize,
+                counts: *mut u8,
+                counts_len: usize,
+                day: u8
+            }
+            .to_string()
+        );
+        assert_eq!(
+            quote! { #(#stmts)* }.to_string(),
+            quote! {
+                let values = unsafe { ::core::slice::from_raw_parts(values, values_len) };
+                let counts = unsafe { ::core::slice::from_raw_parts_mut(counts, counts_len) };
+            }
+            .to_string()
+        );
+    }
+
+    #[test]
+    fn test_array_consts_exported() {
+        let mut file: File = parse_quote! {
+            #[repr(transparent)]
+            pub struct Thing(pub u8);
+
+            impl Thing {
+                pub const ALL: [Self; 2] = [Self(0), Self(1)];
+            }
+        };
+        RustFfi::new(
+            vec![format_ident!("Thing")],
+            parse_quote! { crate },
+            "jelal_",
+        )
+        .visit_file_mut(&mut file);
+        let output = file.to_token_stream().to_string();
+        assert!(output.contains(
+            &quote! {
+                #[unsafe(export_name = "THING_ALL")]
+                pub static _THING_ALL: [u8; 2] = THING_ALL;
+            }
+            .to_string()
+        ));
+    }
+
+    #[test]
+    fn test_fieldless_enums() {
+        let mut file: File = parse_quote! {
+            #[repr(u8)]
+            pub enum Side {
+                Left = 0,
+                Right = 1,
+            }
+
+            pub enum Shape {
+                Circle(u8),
+            }
+
+            impl Side {
+                pub const fn flip(&self) -> Self {
+                    match self {
+                        Self::Left => Self::Right,
+                        Self::Right => Self::Left,
+                    }
+                }
+            }
+        };
+        RustFfi::new(
+            vec![format_ident!("Side"), format_ident!("Shape")],
+            parse_quote! { crate },
+            "jelal_",
+        )
+        .visit_file_mut(&mut file);
+        let output = file.to_token_stream().to_string();
+        assert!(output.contains("pub enum Side"));
+        assert!(!output.contains("enum Shape"));
+        assert!(output.contains("impl From < Side > for crate :: Side"));
+        // passed by value since WASM does not accept enum references
+        assert!(output.contains("fn side_flip (this : Side)"));
+    }
+}
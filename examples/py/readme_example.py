@@ -5,7 +5,7 @@ ordinal = 1 * 31 + 13
 # or using monthday
 monthday = MonthDay(2, 13)
 # function equals are available (discouraged)
-assert monthday.ext_cmp(_monthday_new(2, 13)) == 0
+assert monthday.ext_cmp(jelal_monthday_new(2, 13)) == 0
 
 ordinal_from_monthday = monthday.to_ordinal()
 assert ordinal != ordinal_from_monthday
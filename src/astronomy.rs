@@ -0,0 +1,350 @@
+//! Astronomical calculation of the Jalali new year (Nowruz).
+//!
+//! This is the algorithm of "Calendrical Calculations" (Reingold and Dershowitz) for the Persian
+//! astronomical calendar, modified to use the 52.5 degrees east meridian (Iran Standard Time)
+//! instead of Tehran. A year starts on the day whose apparent noon on that meridian is after the
+//! March equinox.
+//!
+//! Moments are in "Rata Die" (fixed days since 0001-01-01 Gregorian as 1) and angles in degrees.
+//! Since `libm` is not available in `no_std` and `f64` methods are not `const`, the required math
+//! is implemented here as well.
+//!
+//! This is too slow to run on each call and only checks the precalculated leap years in the tests.
+
+/// Fixed day of the first day of the first year of the calendar (Julian 622-03-19).
+const EPOCH: f64 = 226896.0;
+
+/// Noon of Gregorian 2000-01-01 (fixed).
+const J2000: f64 = 730120.5;
+
+/// Average days in a tropical year.
+const MEAN_TROPICAL_YEAR: f64 = 365.242189;
+
+/// Longitude of the meridian of Iran Standard Time.
+const LONGITUDE: f64 = 52.5;
+
+/// Coefficients of the sine terms in the longitude of the sun as `(x, y, z)`: `x * sin(y + z * c)`.
+const SOLAR_LONGITUDE_TERMS: [(f64, f64, f64); 49] = [
+    (403406.0, 270.54861, 0.9287892),
+    (195207.0, 340.19128, 35999.1376958),
+    (119433.0, 63.91854, 35999.4089666),
+    (112392.0, 331.26220, 35998.7287385),
+    (3891.0, 317.843, 71998.20261),
+    (2819.0, 86.631, 71998.4403),
+    (1721.0, 240.052, 36000.35726),
+    (660.0, 310.26, 71997.4812),
+    (350.0, 247.23, 32964.4678),
+    (334.0, 260.87, -19.4410),
+    (314.0, 297.82, 445267.1117),
+    (268.0, 343.14, 45036.8840),
+    (242.0, 166.79, 3.1008),
+    (234.0, 81.53, 22518.4434),
+    (158.0, 3.50, -19.9739),
+    (132.0, 132.75, 65928.9345),
+    (129.0, 182.95, 9038.0293),
+    (114.0, 162.03, 3034.7684),
+    (99.0, 29.8, 33718.148),
+    (93.0, 266.4, 3034.448),
+    (86.0, 249.2, -2280.773),
+    (78.0, 157.6, 29929.992),
+    (72.0, 257.8, 31556.493),
+    (68.0, 185.1, 149.588),
+    (64.0, 69.9, 9037.750),
+    (46.0, 8.0, 107997.405),
+    (38.0, 197.1, -4444.176),
+    (37.0, 250.4, 151.771),
+    (32.0, 65.3, 67555.316),
+    (29.0, 162.7, 31556.080),
+    (28.0, 341.5, -4561.540),
+    (27.0, 291.6, 107996.706),
+    (27.0, 98.5, 1221.655),
+    (25.0, 146.7, 62894.167),
+    (24.0, 110.0, 31437.369),
+    (21.0, 5.2, 14578.298),
+    (21.0, 342.6, -31931.757),
+    (20.0, 230.9, 34777.243),
+    (18.0, 256.1, 1221.999),
+    (17.0, 45.3, 62894.511),
+    (14.0, 242.9, -4442.039),
+    (13.0, 115.2, 107997.909),
+    (13.0, 151.8, 119.066),
+    (13.0, 285.3, 16859.071),
+    (12.0, 53.3, -4.578),
+    (10.0, 126.6, 26895.292),
+    (10.0, 205.7, -39.127),
+    (10.0, 85.9, 12297.536),
+    (10.0, 146.1, 90073.778),
+];
+
+/// Return the first fixed day of the given (non-zero) year.
+pub(crate) const fn new_year(year: i32) -> i64 {
+    // the years before the first year start from 0 in this arithmetic
+    let elapsed = if year > 0 {
+        year as i64 - 1
+    } else {
+        year as i64
+    };
+    new_year_on_or_before(EPOCH + 180.0 + floor(MEAN_TROPICAL_YEAR * elapsed as f64))
+}
+
+/// Return the fixed day of the last new year on or before the given fixed day.
+const fn new_year_on_or_before(date: f64) -> i64 {
+    let approx = estimate_prior_spring(midday(date));
+    let mut day = floor(approx) - 1.0;
+    // just after the equinox the longitude is small, before it is close to 360
+    while solar_longitude(midday(day)) > 2.0 {
+        day += 1.0;
+    }
+    day as i64
+}
+
+/// Estimate the moment of the last March equinox before the given moment.
+const fn estimate_prior_spring(tee: f64) -> f64 {
+    let rate = MEAN_TROPICAL_YEAR / 360.0;
+    let tau = tee - rate * modulo(solar_longitude(tee), 360.0);
+    let delta = modulo(solar_longitude(tau) + 180.0, 360.0) - 180.0;
+    let estimate = tau - rate * delta;
+    if tee < estimate { tee } else { estimate }
+}
+
+/// Return the universal time of the apparent noon of the given fixed day on [`LONGITUDE`].
+const fn midday(date: f64) -> f64 {
+    let local = date + 0.5;
+    let universal = local - LONGITUDE / 360.0;
+    universal - equation_of_time(universal)
+}
+
+/// Return the longitude of the sun at the given universal moment in `0..360`.
+const fn solar_longitude(tee: f64) -> f64 {
+    let c = julian_centuries(tee);
+    let mut sum = 0.0;
+    let mut i = 0;
+    while i < SOLAR_LONGITUDE_TERMS.len() {
+        let (x, y, z) = SOLAR_LONGITUDE_TERMS[i];
+        sum += x * sin_deg(y + z * c);
+        i += 1;
+    }
+    let lambda = 282.7771834 + 36000.76953744 * c + 0.000005729577951308232 * sum;
+    modulo(lambda + aberration(c) + nutation(c), 360.0)
+}
+
+/// Return the nutation of the sun's longitude in the given centuries.
+const fn nutation(c: f64) -> f64 {
+    let a = 124.90 - 1934.134 * c + 0.002063 * c * c;
+    let b = 201.11 + 72001.5377 * c + 0.00057 * c * c;
+    -0.004778 * sin_deg(a) - 0.0003667 * sin_deg(b)
+}
+
+/// Return the aberration of the sun's longitude in the given centuries.
+const fn aberration(c: f64) -> f64 {
+    0.0000974 * cos_deg(177.63 + 35999.01848 * c) - 0.005575
+}
+
+/// Return the difference of the apparent and mean solar time in days at the given moment.
+const fn equation_of_time(tee: f64) -> f64 {
+    let c = julian_centuries(tee);
+    let lambda = 280.46645 + 36000.76983 * c + 0.0003032 * c * c;
+    let anomaly = 357.52910 + 35999.05030 * c - 0.0001559 * c * c - 0.00000048 * c * c * c;
+    let eccentricity = 0.016708617 - 0.000042037 * c - 0.0000001236 * c * c;
+    let half_obliquity = obliquity(c) / 2.0;
+    let y = sin_deg(half_obliquity) / cos_deg(half_obliquity);
+    let y = y * y;
+
+    let equation = (y * sin_deg(2.0 * lambda) - 2.0 * eccentricity * sin_deg(anomaly)
+        + 4.0 * eccentricity * y * sin_deg(anomaly) * cos_deg(2.0 * lambda)
+        - 0.5 * y * y * sin_deg(4.0 * lambda)
+        - 1.25 * eccentricity * eccentricity * sin_deg(2.0 * anomaly))
+        / (2.0 * core::f64::consts::PI);
+
+    if equation > 0.5 {
+        0.5
+    } else if equation < -0.5 {
+        -0.5
+    } else {
+        equation
+    }
+}
+
+/// Return the obliquity of the ecliptic in the given centuries.
+const fn obliquity(c: f64) -> f64 {
+    23.43929111111111 - 0.013004166666666666 * c - 1.638888888888889e-7 * c * c
+        + 5.036111111111111e-7 * c * c * c
+}
+
+/// Return the Julian centuries since [`J2000`] in dynamical time at the given universal moment.
+const fn julian_centuries(tee: f64) -> f64 {
+    (tee + ephemeris_correction(tee) - J2000) / 36525.0
+}
+
+/// Return the difference of dynamical and universal time in days at the given moment.
+const fn ephemeris_correction(tee: f64) -> f64 {
+    let year = gregorian_year(floor(tee) as i64);
+    let year_f = year as f64;
+    if year >= 2051 && year <= 2150 {
+        let x = (year_f - 1820.0) / 100.0;
+        (-20.0 + 32.0 * x * x + 0.5628 * (2150.0 - year_f)) / 86400.0
+    } else if year >= 2006 && year <= 2050 {
+        let y = year_f - 2000.0;
+        (62.92 + 0.32217 * y + 0.005589 * y * y) / 86400.0
+    } else if year >= 1987 && year <= 2005 {
+        let y = year_f - 2000.0;
+        (63.86 + 0.3345 * y - 0.060374 * y * y
+            + 0.0017275 * pow(y, 3)
+            + 0.000651814 * pow(y, 4)
+            + 0.00002373599 * pow(y, 5))
+            / 86400.0
+    } else if year >= 1800 && year <= 1986 {
+        let c = (gregorian_fixed(year, 7, 1) - gregorian_fixed(1900, 1, 1)) as f64 / 36525.0;
+        if year >= 1900 {
+            polynomial(
+                c,
+                &[
+                    -0.00002, 0.000297, 0.025184, -0.181133, 0.553040, -0.861938, 0.677066,
+                    -0.212591,
+                ],
+            )
+        } else {
+            polynomial(
+                c,
+                &[
+                    -0.000009, 0.003844, 0.083563, 0.865736, 4.867575, 15.845535, 31.332267,
+                    38.291999, 28.316289, 11.636204, 2.043794,
+                ],
+            )
+        }
+    } else if year >= 1700 && year <= 1799 {
+        let y = year_f - 1700.0;
+        polynomial(y, &[8.118780842, -0.005092142, 0.003336121, -0.0000266484]) / 86400.0
+    } else if year >= 1600 && year <= 1699 {
+        let y = year_f - 1600.0;
+        polynomial(y, &[120.0, -0.9808, -0.01532, 1.0 / 7129.0]) / 86400.0
+    } else if year >= 500 && year <= 1599 {
+        let y = (year_f - 1000.0) / 100.0;
+        polynomial(
+            y,
+            &[
+                1574.2,
+                -556.01,
+                71.23472,
+                0.319781,
+                -0.8503463,
+                -0.005050998,
+                0.0083572073,
+            ],
+        ) / 86400.0
+    } else if year >= -499 && year <= 499 {
+        let y = year_f / 100.0;
+        polynomial(
+            y,
+            &[
+                10583.6,
+                -1014.41,
+                33.78311,
+                -5.952053,
+                -0.1798452,
+                0.022174192,
+                0.0090316521,
+            ],
+        ) / 86400.0
+    } else {
+        let x = (year_f - 1820.0) / 100.0;
+        (-20.0 + 32.0 * x * x) / 86400.0
+    }
+}
+
+/// Return the (proleptic) Gregorian year of the given fixed day.
+const fn gregorian_year(date: i64) -> i64 {
+    let d0 = date - 1;
+    let n400 = d0.div_euclid(146097);
+    let d1 = d0.rem_euclid(146097);
+    let n100 = d1 / 36524;
+    let d2 = d1 % 36524;
+    let n4 = d2 / 1461;
+    let d3 = d2 % 1461;
+    let n1 = d3 / 365;
+    let year = 400 * n400 + 100 * n100 + 4 * n4 + n1;
+    if n100 == 4 || n1 == 4 { year } else { year + 1 }
+}
+
+/// Return the fixed day of the given (proleptic) Gregorian date.
+const fn gregorian_fixed(year: i64, month: i64, day: i64) -> i64 {
+    let leap = year.rem_euclid(4) == 0 && !matches!(year.rem_euclid(400), 100 | 200 | 300);
+    let prev = year - 1;
+    let correction = if month <= 2 {
+        0
+    } else if leap {
+        -1
+    } else {
+        -2
+    };
+    365 * prev + prev.div_euclid(4) - prev.div_euclid(100)
+        + prev.div_euclid(400)
+        + (367 * month - 362) / 12
+        + correction
+        + day
+}
+
+/// Evaluate the polynomial with the given coefficients (lowest degree first).
+const fn polynomial(x: f64, coefficients: &[f64]) -> f64 {
+    let mut result = 0.0;
+    let mut i = coefficients.len();
+    while i > 0 {
+        i -= 1;
+        result = result * x + coefficients[i];
+    }
+    result
+}
+
+/// Return `x` to the power of `n`.
+const fn pow(x: f64, n: u32) -> f64 {
+    let mut result = 1.0;
+    let mut i = 0;
+    while i < n {
+        result *= x;
+        i += 1;
+    }
+    result
+}
+
+/// Return the largest integer less than or equal to `x`.
+const fn floor(x: f64) -> f64 {
+    let truncated = x as i64 as f64;
+    if truncated > x {
+        truncated - 1.0
+    } else {
+        truncated
+    }
+}
+
+/// Return `x` modulo `y` with the sign of `y`.
+const fn modulo(x: f64, y: f64) -> f64 {
+    x - y * floor(x / y)
+}
+
+/// Return the sine of the given degrees.
+const fn sin_deg(degrees: f64) -> f64 {
+    // reduce to -180..180 and then to -90..=90 where the series converges fast
+    let mut x = modulo(degrees + 180.0, 360.0) - 180.0;
+    if x > 90.0 {
+        x = 180.0 - x;
+    } else if x < -90.0 {
+        x = -180.0 - x;
+    }
+    let x = x * (core::f64::consts::PI / 180.0);
+
+    let x2 = x * x;
+    let mut term = x;
+    let mut sum = x;
+    let mut n = 1.0;
+    while n < 23.0 {
+        term *= -x2 / ((n + 1.0) * (n + 2.0));
+        sum += term;
+        n += 2.0;
+    }
+    sum
+}
+
+/// Return the cosine of the given degrees.
+const fn cos_deg(degrees: f64) -> f64 {
+    sin_deg(degrees + 90.0)
+}
@@ -0,0 +1,32 @@
+//! Holds the [`Calendar`] trait which describes the days off of a calendar.
+
+use crate::Date;
+
+/// Describes which days are off (weekends and holidays) and which are business days.
+///
+/// The default implementations describe the official Iranian week without any holidays (see
+/// [`DefaultCalendar`]).
+pub trait Calendar {
+    /// Is the given date a weekend (by default only [`crate::Weekday::Friday`] is).
+    ///
+    /// See [`Date::is_weekend`] and [`Date::is_weekend_two_day`].
+    fn is_weekend(&self, date: &Date) -> bool {
+        date.is_weekend()
+    }
+
+    /// Is the given date a holiday (by default no day is).
+    fn is_holiday(&self, _date: &Date) -> bool {
+        false
+    }
+
+    /// Is the given date a business day (neither a weekend nor a holiday).
+    fn is_business_day(&self, date: &Date) -> bool {
+        !self.is_weekend(date) && !self.is_holiday(date)
+    }
+}
+
+/// The official Iranian week (only Friday is off) without any holidays.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct DefaultCalendar;
+
+impl Calendar for DefaultCalendar {}
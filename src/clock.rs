@@ -0,0 +1,45 @@
+//! Holds the functionalities which need to read the system clock.
+
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::{Date, IDayDiff, ToJalali};
+
+/// The number of seconds in a day, ignoring the leap seconds like Unix time.
+const SECS_PER_DAY: u64 = 24 * 60 * 60;
+
+// Not in the main `impl` block since the clock is not available to every FFI.
+impl Date {
+    /// Return the date of today in UTC using the system clock.
+    ///
+    /// See [`Self::today_from_epoch_day`] for a clock independent alternative.
+    pub fn now() -> Self {
+        SystemTime::now().to_jalali()
+    }
+}
+
+/// Convert the date of the given time in UTC (before or after Unix Epoch).
+impl From<SystemTime> for Date {
+    fn from(value: SystemTime) -> Self {
+        Self::today_from_epoch_day(epoch_day(value))
+    }
+}
+
+impl ToJalali for SystemTime {
+    fn to_jalali(&self) -> Date {
+        (*self).into()
+    }
+}
+
+/// Return the number of days passed since Unix Epoch (negative if before it) until the given time.
+pub(crate) fn epoch_day(time: SystemTime) -> IDayDiff {
+    match time.duration_since(UNIX_EPOCH) {
+        // after the epoch, the day is not complete yet so it is floored
+        Ok(since) => (since.as_secs() / SECS_PER_DAY)
+            .try_into()
+            .unwrap_or(IDayDiff::MAX),
+        // before the epoch, any part of a day is in the day before so it is ceiled
+        Err(e) => (e.duration().as_secs().div_ceil(SECS_PER_DAY))
+            .try_into()
+            .map_or(IDayDiff::MIN, |days: IDayDiff| -days),
+    }
+}
@@ -0,0 +1,96 @@
+//! Holds the conversions between [`Date`] and the dates of other crates.
+//!
+//! The conversions only consider the date part of the values and ignore time zones, meaning a UTC
+//! date time is converted to the Jalali date of that day in UTC.
+
+#[cfg(feature = "chrono")]
+use ::chrono::{DateTime, Datelike, NaiveDate, Utc};
+
+use crate::Date;
+
+/// Convert the dates of other crates (and the standard library) to their Jalali [`Date`].
+///
+/// Each implementation counts the signed days from Unix Epoch (1970-01-01) so the dates before the
+/// epoch land before [`Date::EPOCH`] and the ones after it land after it. Like the rest of this
+/// module, time zones are ignored and only the date (in UTC if it is a time) is considered.
+pub trait ToJalali {
+    /// Return the Jalali date equal to this value.
+    fn to_jalali(&self) -> Date;
+}
+
+#[cfg(feature = "chrono")]
+impl ToJalali for NaiveDate {
+    fn to_jalali(&self) -> Date {
+        (*self).into()
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl ToJalali for DateTime<Utc> {
+    fn to_jalali(&self) -> Date {
+        (*self).into()
+    }
+}
+
+#[cfg(feature = "time")]
+impl ToJalali for ::time::Date {
+    fn to_jalali(&self) -> Date {
+        (*self).into()
+    }
+}
+
+/// Days from the first day of the common era to Unix Epoch (1970-01-01).
+#[cfg(feature = "chrono")]
+const UNIX_EPOCH_DAYS_FROM_CE: i64 = 719_163;
+
+#[cfg(feature = "chrono")]
+impl From<NaiveDate> for Date {
+    fn from(value: NaiveDate) -> Self {
+        let days = value.num_days_from_ce() as i64 - UNIX_EPOCH_DAYS_FROM_CE;
+        Self::from_epoch_days(days)
+    }
+}
+
+/// Convert the date of the given UTC date time, ignoring the time.
+#[cfg(feature = "chrono")]
+impl From<DateTime<Utc>> for Date {
+    fn from(value: DateTime<Utc>) -> Self {
+        value.date_naive().into()
+    }
+}
+
+/// The Julian day number of Unix Epoch (1970-01-01).
+#[cfg(feature = "time")]
+const UNIX_EPOCH_JULIAN_DAY: i64 = 2_440_588;
+
+#[cfg(feature = "time")]
+impl From<::time::Date> for Date {
+    fn from(value: ::time::Date) -> Self {
+        let days = value.to_julian_day() as i64 - UNIX_EPOCH_JULIAN_DAY;
+        Self::from_epoch_days(days)
+    }
+}
+
+// Not in the main `impl` block since the other crates are not available to FFI.
+impl Date {
+    /// Return the [`chrono::NaiveDate`] equal to this date (saturated to its limits).
+    #[cfg(feature = "chrono")]
+    pub fn to_chrono_naive(&self) -> NaiveDate {
+        let (year, month, day) = self.to_gregorian();
+        NaiveDate::from_ymd_opt(year, month as u32, day as u32).unwrap_or(
+            if self.cmp(&Self::EPOCH).is_lt() {
+                NaiveDate::MIN
+            } else {
+                NaiveDate::MAX
+            },
+        )
+    }
+
+    /// Return the [`time::Date`] equal to this date or an error if it is out of its range.
+    #[cfg(feature = "time")]
+    pub fn to_time_date(&self) -> Result<::time::Date, ::time::error::ComponentRange> {
+        let julian_day = self.diff_epoch() as i64 + UNIX_EPOCH_JULIAN_DAY;
+        // out of the range of `i32` is out of the range of `time::Date` too
+        ::time::Date::from_julian_day(julian_day.try_into().unwrap_or(i32::MAX))
+    }
+}
@@ -0,0 +1,77 @@
+//! Holds the errors of the strict (non-saturating) constructors, operations and parsing.
+
+use core::{
+    fmt::Display,
+    num::{IntErrorKind, ParseIntError},
+};
+
+/// The reason a value was rejected instead of being saturated to a valid one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DateError {
+    /// The year is 0 which does not exist (see [`crate::Year::ZERO_REPLACEMENT`]).
+    YearZero,
+    /// The month is not in [`crate::Month::MIN`] to [`crate::Month::MAX`].
+    MonthOutOfRange,
+    /// The day does not exist in its month (leap correct).
+    DayOutOfRange,
+}
+
+impl Display for DateError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::YearZero => write!(f, "year 0 does not exist"),
+            Self::MonthOutOfRange => write!(f, "month is out of range"),
+            Self::DayOutOfRange => write!(f, "day does not exist in the month"),
+        }
+    }
+}
+
+impl core::error::Error for DateError {}
+
+/// The reason a text could not be parsed as a date (see `FromStr` of [`crate::Date`]).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParseDateError {
+    /// There are less than three fields (year, month and day) separated by `/` or `-`.
+    TooFewFields,
+    /// There are more than three fields (year, month and day) separated by `/` or `-`.
+    TooManyFields,
+    /// A field is empty or has characters other than digits.
+    NonNumeric,
+    /// A field is too large (or small) to fit its type even before being saturated.
+    OutOfRange,
+}
+
+impl From<ParseIntError> for ParseDateError {
+    fn from(value: ParseIntError) -> Self {
+        match value.kind() {
+            IntErrorKind::PosOverflow | IntErrorKind::NegOverflow => Self::OutOfRange,
+            _ => Self::NonNumeric,
+        }
+    }
+}
+
+impl Display for ParseDateError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::TooFewFields => write!(f, "date has less than 3 fields"),
+            Self::TooManyFields => write!(f, "date has more than 3 fields"),
+            Self::NonNumeric => write!(f, "date field is not a number"),
+            Self::OutOfRange => write!(f, "date field is out of range"),
+        }
+    }
+}
+
+impl core::error::Error for ParseDateError {}
+
+/// The value an operation saturated to instead of the exact result (see
+/// [`crate::DidSaturate::into_result`]).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Saturated<T>(pub T);
+
+impl<T> Display for Saturated<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "result saturated")
+    }
+}
+
+impl<T: core::fmt::Debug> core::error::Error for Saturated<T> {}
@@ -4,6 +4,7 @@
 #![doc = r" Modify the source that source for control on what to include and how."]
 #![allow(deprecated)]
 #![allow(unused_imports)]
+#![allow(unused_unsafe)]
 #![cfg_attr(feature = "py", allow(unsafe_op_in_unsafe_fn))]
 use crate::ffi::*;
 #[cfg(feature = "py")]
@@ -53,7 +54,7 @@ impl MonthDay {
         unsafe { ::core::mem::transmute(crate::MonthDay::cmp(this, &other.clone().into())) }
     }
 }
-#[doc = " A Jalali valid date.\n\n See [`Year`] for more information about year count. [`Self::MIN`] to [`Self::MAX`] is the\n representable range (not necessarily all correct in leap calculation or conversion). Year 0 is\n not a valid year (see [`Year::ZERO_REPLACEMENT`])."]
+#[doc = " A Jalali valid date.\n\n See [`Year`] for more information about year count. [`Self::MIN`] to [`Self::MAX`] is the\n representable range (not necessarily all correct in leap calculation or conversion, see\n [`Self::is_reliable`]). Year 0 is not a valid year (see [`Year::ZERO_REPLACEMENT`])."]
 #[cfg_attr(feature = "c", repr(C))]
 #[cfg_attr(feature = "wasm", wasm_bindgen)]
 #[cfg_attr(feature = "py", pyclass)]
@@ -67,6 +68,12 @@ pub struct Date {
 #[cfg_attr(feature = "py", pymethods)]
 #[cfg_attr(feature = "wasm", wasm_bindgen)]
 impl Date {
+    #[doc = " Is this date between [`Self::RELIABLE_MIN`] and [`Self::RELIABLE_MAX`].\n\n Outside this range the leap years fall back to the 33-year rule (see [`Year::is_leap`]) so\n conversions and day counts may differ from the real calendar."]
+    pub fn is_reliable(&self) -> bool {
+        let this = self;
+        let this: &crate::Date = &this.clone().into();
+        unsafe { ::core::mem::transmute(crate::Date::is_reliable(this)) }
+    }
     #[doc = " Return how many days on this date will result to the given destination.\n\n This is exactly as [`Self::diff_as_days_strict`] but returns the value only."]
     pub fn diff_as_days(&self, other: Date) -> IDayDiff {
         let this = self;
@@ -79,6 +86,95 @@ impl Date {
         let this: &crate::Date = &this.clone().into();
         unsafe { ::core::mem::transmute(crate::Date::diff_epoch(this)) }
     }
+    #[doc = " Return the number of days since Unix Epoch ([`Self::EPOCH`]) as a continuous day index.\n\n The later dates have larger numbers: the epoch is 0, the day after is 1 and 1969-12-31 is\n -1. This is the reverse of [`Self::from_epoch_days`] and the same as [`Self::diff_epoch`]."]
+    pub fn to_epoch_days(&self) -> IDayDiff {
+        let this = self;
+        let this: &crate::Date = &this.clone().into();
+        unsafe { ::core::mem::transmute(crate::Date::to_epoch_days(this)) }
+    }
+    #[doc = " Return if the day of this date does not exist after adding the given months.\n\n This is true when the day is greater than the length of the target month (e.g. 31st of\n any month moving into a 30 day month), see [`Self::add_months`]."]
+    pub fn month_add_would_clamp(&self, months: IDayDiff) -> bool {
+        let this = self;
+        let this: &crate::Date = &this.clone().into();
+        unsafe { ::core::mem::transmute(crate::Date::month_add_would_clamp(this, months.into())) }
+    }
+    #[doc = " Return how many whole months on this date will result to the given destination.\n\n This is exactly as [`Self::diff_as_months_strict`] but returns the value only."]
+    pub fn diff_as_months(&self, other: Date) -> IDayDiff {
+        let this = self;
+        let this: &crate::Date = &this.clone().into();
+        unsafe { ::core::mem::transmute(crate::Date::diff_as_months(this, other.into())) }
+    }
+    #[doc = " Return how many whole years on this date will result to the given destination.\n\n This is exactly as [`Self::diff_as_years_strict`] but returns the value only."]
+    pub fn diff_as_years(&self, other: Date) -> IDayDiff {
+        let this = self;
+        let this: &crate::Date = &this.clone().into();
+        unsafe { ::core::mem::transmute(crate::Date::diff_as_years(this, other.into())) }
+    }
+    #[doc = " Return the completed years from this date (e.g. a birthdate) to `as_of` (i.e. the age).\n\n The birthday on the 30th of [`Month::MAX`] is on its 29th in non-leap years. The result is\n negative if `as_of` is earlier. This is exactly as `as_of.diff_as_years(self)` (see\n [`Self::diff_as_years`])."]
+    pub fn age_in_years(&self, as_of: Date) -> IDayDiff {
+        let this = self;
+        let this: &crate::Date = &this.clone().into();
+        unsafe { ::core::mem::transmute(crate::Date::age_in_years(this, as_of.into())) }
+    }
+    #[doc = " Return the whole months between the dates in a type which never overflows."]
+    fn diff_as_months_wide(&self, other: &Date) -> i64 {
+        let this = self;
+        let this: &crate::Date = &this.clone().into();
+        unsafe {
+            ::core::mem::transmute(crate::Date::diff_as_months_wide(
+                this,
+                &other.clone().into(),
+            ))
+        }
+    }
+    #[doc = " Return if the given date is the day after or before this date."]
+    pub fn is_consecutive_day(&self, other: &Date) -> bool {
+        let this = self;
+        let this: &crate::Date = &this.clone().into();
+        unsafe {
+            ::core::mem::transmute(crate::Date::is_consecutive_day(this, &other.clone().into()))
+        }
+    }
+    #[doc = " Return if the given date is in the month after or before the month of this date."]
+    pub fn is_consecutive_month(&self, other: &Date) -> bool {
+        let this = self;
+        let this: &crate::Date = &this.clone().into();
+        unsafe {
+            ::core::mem::transmute(crate::Date::is_consecutive_month(
+                this,
+                &other.clone().into(),
+            ))
+        }
+    }
+    #[doc = " Return if the given date is in the year after or before the year of this date."]
+    pub fn is_consecutive_year(&self, other: &Date) -> bool {
+        let this = self;
+        let this: &crate::Date = &this.clone().into();
+        unsafe {
+            ::core::mem::transmute(crate::Date::is_consecutive_year(
+                this,
+                &other.clone().into(),
+            ))
+        }
+    }
+    #[doc = " Return the first day of the month of this date."]
+    pub fn first_day_of_month(&self) -> Date {
+        let this = self;
+        let this: &crate::Date = &this.clone().into();
+        unsafe { ::core::mem::transmute(crate::Date::first_day_of_month(this)) }
+    }
+    #[doc = " Return the last day of the month of this date (leap correct)."]
+    pub fn last_day_of_month(&self) -> Date {
+        let this = self;
+        let this: &crate::Date = &this.clone().into();
+        unsafe { ::core::mem::transmute(crate::Date::last_day_of_month(this)) }
+    }
+    #[doc = " Return how many days are left until the given date or 0 if it is today or in the past.\n\n This is an unsigned countdown unlike [`Self::diff_as_days`]."]
+    pub fn days_until(&self, target: Date) -> UDayDiff {
+        let this = self;
+        let this: &crate::Date = &this.clone().into();
+        unsafe { ::core::mem::transmute(crate::Date::days_until(this, target.into())) }
+    }
     #[doc = " Return the value of inner `Self::year` for this instance."]
     pub fn year(&self) -> Year {
         let this = self;
@@ -91,14 +187,95 @@ impl Date {
         let this: &crate::Date = &this.clone().into();
         unsafe { ::core::mem::transmute(crate::Date::ordinal(this)) }
     }
+    #[doc = " Return an unsigned key which is ordered exactly as dates are (earlier is smaller).\n\n The year (offset to be unsigned) is kept in the higher bits and the ordinal in the lower\n ones, so the keys are ordered, even across year 0 and [`Self::EPOCH`], but not consecutive.\n Use [`Self::from_sort_key`] for the inverse."]
+    pub fn to_sort_key(&self) -> u64 {
+        let this = self;
+        let this: &crate::Date = &this.clone().into();
+        unsafe { ::core::mem::transmute(crate::Date::to_sort_key(this)) }
+    }
+    #[doc = " Return the day of the week of this date."]
+    pub fn weekday(&self) -> Weekday {
+        let this = self;
+        let this: &crate::Date = &this.clone().into();
+        unsafe { ::core::mem::transmute(crate::Date::weekday(this)) }
+    }
+    #[doc = " Return the season of this date."]
+    pub fn season(&self) -> Season {
+        let this = self;
+        let this: &crate::Date = &this.clone().into();
+        unsafe { ::core::mem::transmute(crate::Date::season(this)) }
+    }
+    #[doc = " Is this date a weekend in the official Iranian week (only [`Weekday::Friday`]).\n\n See [`Self::is_weekend_two_day`] for the Thursday and Friday weekends."]
+    pub fn is_weekend(&self) -> bool {
+        let this = self;
+        let this: &crate::Date = &this.clone().into();
+        unsafe { ::core::mem::transmute(crate::Date::is_weekend(this)) }
+    }
+    #[doc = " Is this date a weekend if both [`Weekday::Thursday`] and [`Weekday::Friday`] are off."]
+    pub fn is_weekend_two_day(&self) -> bool {
+        let this = self;
+        let this: &crate::Date = &this.clone().into();
+        unsafe { ::core::mem::transmute(crate::Date::is_weekend_two_day(this)) }
+    }
+    #[doc = " Return the first day of the week of this date given the day that weeks start on."]
+    pub fn start_of_week(&self, start: WeekStart) -> Date {
+        let this = self;
+        let this: &crate::Date = &this.clone().into();
+        unsafe { ::core::mem::transmute(crate::Date::start_of_week(this, start.into())) }
+    }
+    #[doc = " Return the start of the week of this date or the next one, whichever is nearer.\n\n The days up to 3 days after `start` round back to the start of their week and the rest\n round forward to the start of the next week. Since the days are whole, a tie (3.5 days) is\n impossible and the middle day of the week (3 days after `start`) rounds back."]
+    pub fn round_to_week(&self, start: WeekStart) -> Date {
+        let this = self;
+        let this: &crate::Date = &this.clone().into();
+        unsafe { ::core::mem::transmute(crate::Date::round_to_week(this, start.into())) }
+    }
+    #[doc = " Return the day of the year of this date (1 to 366, same as [`Self::ordinal`])."]
+    pub fn day_of_year(&self) -> UOrdinal {
+        let this = self;
+        let this: &crate::Date = &this.clone().into();
+        unsafe { ::core::mem::transmute(crate::Date::day_of_year(this)) }
+    }
+    #[doc = " Return the 1-based week of the year of this date with weeks starting on Saturday (Shanbe).\n\n The first week is the one containing the 1st of Farvardin, even if it is partial (e.g. when\n the year starts on a Friday, the Saturday after is already in the 2nd week). So the result\n is from 1 to 54."]
+    pub fn week_of_year(&self) -> u8 {
+        let this = self;
+        let this: &crate::Date = &this.clone().into();
+        unsafe { ::core::mem::transmute(crate::Date::week_of_year(this)) }
+    }
+    #[doc = " Is the year of this date a leap year (see [`Year::is_leap`])."]
+    pub fn is_leap_year(&self) -> bool {
+        let this = self;
+        let this: &crate::Date = &this.clone().into();
+        unsafe { ::core::mem::transmute(crate::Date::is_leap_year(this)) }
+    }
+    #[doc = " Return the number of days in the year of this date (365 or 366 for leaps)."]
+    pub fn days_in_year(&self) -> UOrdinal {
+        let this = self;
+        let this: &crate::Date = &this.clone().into();
+        unsafe { ::core::mem::transmute(crate::Date::days_in_year(this)) }
+    }
+    #[doc = " Return how much of the month is passed (including today) in thousandths (1 to 1000)."]
+    pub fn month_progress_permille(&self) -> u16 {
+        let this = self;
+        let this: &crate::Date = &this.clone().into();
+        unsafe { ::core::mem::transmute(crate::Date::month_progress_permille(this)) }
+    }
+    #[doc = " Return the day of the year (from 1) of the proleptic Gregorian date equal to this date."]
+    pub fn gregorian_ordinal(&self) -> u16 {
+        let this = self;
+        let this: &crate::Date = &this.clone().into();
+        unsafe { ::core::mem::transmute(crate::Date::gregorian_ordinal(this)) }
+    }
     #[doc = " Convert this [`Self::to_jtm`] but on the given struct."]
     #[cfg(feature = "c")]
     pub fn update_jtm(&self, jtm: &mut tm) {
         let this = self;
         let this: &crate::Date = &this.clone().into();
-        unsafe { ::core::mem::transmute(crate::Date::update_jtm(this, &mut jtm.clone().into())) }
+        let mut __jtm = jtm.clone().into();
+        let result = crate::Date::update_jtm(this, &mut __jtm);
+        *jtm = __jtm.into();
+        unsafe { ::core::mem::transmute(result) }
     }
-    #[doc = " Create an [`ffi::tm`] from this date in Jalali.\n\n If the aim is not to create a new instance and update an already created `tm`, use\n [`Self::update_jtm`].\n\n See its documents for how this struct's values should be interpreted when the date is\n assumed to be Jalali. In short, this is exactly as in C but year doesn't have an offset and\n only year, month, ordinal and month day are set.\n\n There are no `from_jtm` equal since there are many ways interprete how this should be done,\n (based on ordinal `yday` or `year`, `mon`, `mday` fields to name two).\n\n To convert this value into a `tm` (Gregorian) use [`Self::diff_epoch`] and then convert that\n to seconds to use with `localtime` and `gmtime`."]
+    #[doc = " Create an [`ffi::tm`] from this date in Jalali.\n\n If the aim is not to create a new instance and update an already created `tm`, use\n [`Self::update_jtm`].\n\n See its documents for how this struct's values should be interpreted when the date is\n assumed to be Jalali. In short, this is exactly as in C but year doesn't have an offset and\n only year, month, ordinal, month day and week day are set.\n\n There are many ways to interpret a `tm` back, see [`Self::from_jtm_ymd`] (`year`, `mon` and\n `mday` fields) and [`Self::from_jtm_yday`] (`year` and `yday` fields).\n\n To convert this value into a `tm` (Gregorian) use [`Self::diff_epoch`] and then convert that\n to seconds to use with `localtime` and `gmtime`."]
     #[cfg(feature = "c")]
     pub fn to_jtm(&self) -> tm {
         let this = self;
@@ -149,6 +326,18 @@ impl Month {
         let this: &crate::Month = &this.clone().into();
         unsafe { ::core::mem::transmute(crate::Month::to_ordinal_assume_zero(this)) }
     }
+    #[doc = " Return the number of days in this month of the given year (leap correct)."]
+    pub fn days_in(&self, year: IYear) -> UMonthDay {
+        let this = self;
+        let this: &crate::Month = &this.clone().into();
+        unsafe { ::core::mem::transmute(crate::Month::days_in(this, year.into())) }
+    }
+    #[doc = " Return the season which this month is in."]
+    pub fn season(&self) -> Season {
+        let this = self;
+        let this: &crate::Month = &this.clone().into();
+        unsafe { ::core::mem::transmute(crate::Month::season(this)) }
+    }
     #[doc = " Return the owned types of this value."]
     pub fn get(&self) -> UMonth {
         let this = self;
@@ -173,6 +362,30 @@ pub struct Ordinal(UOrdinal);
 #[cfg_attr(feature = "py", pymethods)]
 #[cfg_attr(feature = "wasm", wasm_bindgen)]
 impl Ordinal {
+    #[doc = " Convert this day of the year to its month and day (leap correct).\n\n This is the reverse of [`MonthDay::to_ordinal`], for example `Ordinal::new(44)` is the 13th\n of the second month (`2/13`) and [`Self::MAX`] is the 30th of the last."]
+    pub fn to_month_day(&self) -> MonthDay {
+        let this = self;
+        let this: &crate::Ordinal = &this.clone().into();
+        unsafe { ::core::mem::transmute(crate::Ordinal::to_month_day(this)) }
+    }
+    #[doc = " Is this day in the first half of the year (before [`Self::MID`]).\n\n The first half ends on day 186 which is the 31st of the sixth month."]
+    pub fn is_first_half(&self) -> bool {
+        let this = self;
+        let this: &crate::Ordinal = &this.clone().into();
+        unsafe { ::core::mem::transmute(crate::Ordinal::is_first_half(this)) }
+    }
+    #[doc = " Is this day in the second half of the year (on or after [`Self::MID`])."]
+    pub fn is_second_half(&self) -> bool {
+        let this = self;
+        let this: &crate::Ordinal = &this.clone().into();
+        unsafe { ::core::mem::transmute(crate::Ordinal::is_second_half(this)) }
+    }
+    #[doc = " Return the half of the year this day is in, 1 or 2 (see [`Self::is_first_half`])."]
+    pub fn half(&self) -> u8 {
+        let this = self;
+        let this: &crate::Ordinal = &this.clone().into();
+        unsafe { ::core::mem::transmute(crate::Ordinal::half(this)) }
+    }
     #[doc = " Return the owned types of this value."]
     pub fn get(&self) -> UOrdinal {
         let this = self;
@@ -197,13 +410,25 @@ pub struct Year(IYear);
 #[cfg_attr(feature = "py", pymethods)]
 #[cfg_attr(feature = "wasm", wasm_bindgen)]
 impl Year {
+    #[doc = " Return the 1-based century of this year (e.g. 14 for 1300 to 1399).\n\n Negative years mirror the positive ones since there is no year 0 (-1 is the year before 1),\n so -1 to -99 is century -1, -100 to -199 is -2 and so on."]
+    pub fn century(&self) -> i32 {
+        let this = self;
+        let this: &crate::Year = &this.clone().into();
+        unsafe { ::core::mem::transmute(crate::Year::century(this)) }
+    }
+    #[doc = " Return the 1-based decade of this year (e.g. 141 for 1400 to 1409).\n\n Negative years are mirrored the same way as [`Self::century`]."]
+    pub fn decade(&self) -> i32 {
+        let this = self;
+        let this: &crate::Year = &this.clone().into();
+        unsafe { ::core::mem::transmute(crate::Year::decade(this)) }
+    }
     #[doc = " A search into [`Self::NON_LEAP_CORRECTION`]."]
     pub fn is_no_leap_correction(&self) -> bool {
         let this = self;
         let this: &crate::Year = &this.clone().into();
         unsafe { ::core::mem::transmute(crate::Year::is_no_leap_correction(this)) }
     }
-    #[doc = " Is this year a leap year (366 days instead of 365).\n\n Calculated using the 33-year rule. Taken from\n <https://github.com/unicode-org/icu4x/blob/3e3da0a0a34bfe3056d0f89183270ea683f4a23c/utils/calendrical_calculations/src/persian.rs#L161C1-L173C2>"]
+    #[doc = " Is this year a leap year (366 days instead of 365).\n\n Only the years from [`Self::ASTRONOMICAL_MIN`] to [`Self::ASTRONOMICAL_MAX`] are\n astronomical: a year is leap when the next new year is 366 days after its own, as calculated\n beforehand by the algorithm based on the 52.5 degrees east meridian. Outside them the\n 33-year rule is used (shifted at each end to continue the calculated years) since the\n rotation of the earth is not known well enough to calculate the new year, so those years\n are only approximate."]
     pub fn is_leap(&self) -> bool {
         let this = self;
         let this: &crate::Year = &this.clone().into();
@@ -215,6 +440,12 @@ impl Year {
         let this: &crate::Year = &this.clone().into();
         unsafe { ::core::mem::transmute(crate::Year::max_ordinal(this)) }
     }
+    #[doc = " Return the date at the given progress through this year in per mille (leap correct).\n\n 0 is the first day of the year and 1000 is the last. Greater values are clamped to 1000."]
+    pub fn date_at_fraction(&self, permille: u16) -> Date {
+        let this = self;
+        let this: &crate::Year = &this.clone().into();
+        unsafe { ::core::mem::transmute(crate::Year::date_at_fraction(this, permille.into())) }
+    }
     #[doc = " Return the owned types of this value."]
     pub fn get(&self) -> IYear {
         let this = self;
@@ -230,58 +461,230 @@ impl Year {
         unsafe { ::core::mem::transmute(crate::Year::cmp(this, &other)) }
     }
 }
+#[doc = " A season of the Jalali year, each made of three consecutive months."]
+#[cfg_attr(feature = "c", repr(u8))]
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+#[cfg_attr(feature = "py", pyclass(eq, eq_int))]
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum Season {
+    #[doc = " Bahar (spring), Farvardin to Khordad."]
+    Spring = 0,
+    #[doc = " Tabestan (summer), Tir to Shahrivar."]
+    Summer = 1,
+    #[doc = " Paeez (autumn), Mehr to Azar."]
+    Autumn = 2,
+    #[doc = " Zemestan (winter), Dey to Esfand."]
+    Winter = 3,
+}
+#[doc = " A day of the week, ordered from the first day of the Jalali week."]
+#[cfg_attr(feature = "c", repr(u8))]
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+#[cfg_attr(feature = "py", pyclass(eq, eq_int))]
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum Weekday {
+    #[doc = " Shanbe, the first day of the Jalali week."]
+    Saturday = 0,
+    #[doc = " Yekshanbe."]
+    Sunday = 1,
+    #[doc = " Doshanbe."]
+    Monday = 2,
+    #[doc = " Seshanbe."]
+    Tuesday = 3,
+    #[doc = " Chaharshanbe."]
+    Wednesday = 4,
+    #[doc = " Panjshanbe."]
+    Thursday = 5,
+    #[doc = " Jomeh, the last day of the Jalali week."]
+    Friday = 6,
+}
+#[doc = " The day which a week is assumed to start on (see [`Weekday::MIN`] for the Jalali default)."]
+pub type WeekStart = Weekday;
+#[cfg_attr(feature = "py", pymethods)]
+impl Weekday {
+    #[doc = " Return the day of the week as in `tm_wday` of C's `struct tm` (0 to 6, from Sunday)."]
+    pub fn to_c_wday(&self) -> u8 {
+        let this = self;
+        let this: &crate::Weekday = &this.clone().into();
+        unsafe { ::core::mem::transmute(crate::Weekday::to_c_wday(this)) }
+    }
+    #[doc = " Return how many days are passed since the given start of the week (0 to 6)."]
+    pub fn days_since(&self, start: WeekStart) -> u8 {
+        let this = self;
+        let this: &crate::Weekday = &this.clone().into();
+        unsafe { ::core::mem::transmute(crate::Weekday::days_since(this, start.into())) }
+    }
+}
 #[cfg(feature = "py")]
 #[pymodule(name = "jelal")]
 fn __pymodule(m: &Bound<'_, PyModule>) -> PyResult<()> {
-    m.add_function(wrap_pyfunction!(_year_cmp, m)?)?;
-    m.add_function(wrap_pyfunction!(_year_get, m)?)?;
-    m.add_function(wrap_pyfunction!(_year_max_ordinal, m)?)?;
-    m.add_function(wrap_pyfunction!(_year_is_leap, m)?)?;
-    m.add_function(wrap_pyfunction!(_year_is_no_leap_correction, m)?)?;
-    m.add_function(wrap_pyfunction!(_year_new, m)?)?;
+    m.add_function(wrap_pyfunction!(jelal_weekday_days_since, m)?)?;
+    m.add_function(wrap_pyfunction!(jelal_weekday_to_c_wday, m)?)?;
+    m.add_function(wrap_pyfunction!(jelal_weekday_add_days, m)?)?;
+    m.add_class::<Weekday>()?;
+    m.add_function(wrap_pyfunction!(jelal_season_from_month, m)?)?;
+    m.add_class::<Season>()?;
+    m.add_function(wrap_pyfunction!(jelal_year_cmp, m)?)?;
+    m.add_function(wrap_pyfunction!(jelal_year_get, m)?)?;
+    m.add_function(wrap_pyfunction!(jelal_year_date_at_fraction, m)?)?;
+    m.add_function(wrap_pyfunction!(jelal_year_max_ordinal, m)?)?;
+    m.add_function(wrap_pyfunction!(jelal_year_is_leap, m)?)?;
+    m.add_function(wrap_pyfunction!(jelal_year_is_no_leap_correction, m)?)?;
+    m.add_function(wrap_pyfunction!(jelal_year_decade, m)?)?;
+    m.add_function(wrap_pyfunction!(jelal_year_century, m)?)?;
+    m.add_function(wrap_pyfunction!(jelal_year_new, m)?)?;
     m.add_class::<Year>()?;
-    m.add_function(wrap_pyfunction!(_ordinal_cmp, m)?)?;
-    m.add_function(wrap_pyfunction!(_ordinal_get, m)?)?;
-    m.add_function(wrap_pyfunction!(_ordinal_new, m)?)?;
+    m.add_function(wrap_pyfunction!(jelal_ordinal_cmp, m)?)?;
+    m.add_function(wrap_pyfunction!(jelal_ordinal_get, m)?)?;
+    m.add_function(wrap_pyfunction!(jelal_ordinal_half, m)?)?;
+    m.add_function(wrap_pyfunction!(jelal_ordinal_is_second_half, m)?)?;
+    m.add_function(wrap_pyfunction!(jelal_ordinal_is_first_half, m)?)?;
+    m.add_function(wrap_pyfunction!(jelal_ordinal_to_month_day, m)?)?;
+    m.add_function(wrap_pyfunction!(jelal_ordinal_new, m)?)?;
     m.add_class::<Ordinal>()?;
-    m.add_function(wrap_pyfunction!(_month_cmp, m)?)?;
-    m.add_function(wrap_pyfunction!(_month_get, m)?)?;
-    m.add_function(wrap_pyfunction!(_month_new, m)?)?;
-    m.add_function(wrap_pyfunction!(_month_to_ordinal_assume_zero, m)?)?;
+    m.add_function(wrap_pyfunction!(jelal_month_cmp, m)?)?;
+    m.add_function(wrap_pyfunction!(jelal_month_get, m)?)?;
+    m.add_function(wrap_pyfunction!(jelal_month_season, m)?)?;
+    m.add_function(wrap_pyfunction!(jelal_month_days_in, m)?)?;
+    m.add_function(wrap_pyfunction!(jelal_month_new, m)?)?;
+    m.add_function(wrap_pyfunction!(jelal_month_to_ordinal_assume_zero, m)?)?;
     m.add_class::<Month>()?;
-    m.add_function(wrap_pyfunction!(_date_ext_from_iyear, m)?)?;
-    m.add_function(wrap_pyfunction!(_date_ext_from_year, m)?)?;
-    m.add_function(wrap_pyfunction!(_date_ext_cmp, m)?)?;
-    m.add_function(wrap_pyfunction!(_date_cmp, m)?)?;
+    m.add_function(wrap_pyfunction!(jelal_date_ext_from_iyear, m)?)?;
+    m.add_function(wrap_pyfunction!(jelal_date_ext_from_year, m)?)?;
+    m.add_function(wrap_pyfunction!(jelal_date_ext_cmp, m)?)?;
+    m.add_function(wrap_pyfunction!(jelal_date_cmp, m)?)?;
+    m.add_function(wrap_pyfunction!(jelal_date_clamp, m)?)?;
+    m.add_function(wrap_pyfunction!(jelal_date_min, m)?)?;
+    m.add_function(wrap_pyfunction!(jelal_date_max, m)?)?;
+    #[cfg(feature = "c")]
+    m.add_function(wrap_pyfunction!(jelal_date_from_jtm_yday, m)?)?;
     #[cfg(feature = "c")]
-    m.add_function(wrap_pyfunction!(_date_to_jtm, m)?)?;
+    m.add_function(wrap_pyfunction!(jelal_date_from_jtm_ymd, m)?)?;
     #[cfg(feature = "c")]
-    m.add_function(wrap_pyfunction!(_date_update_jtm, m)?)?;
-    m.add_function(wrap_pyfunction!(_date_ordinal, m)?)?;
-    m.add_function(wrap_pyfunction!(_date_year, m)?)?;
-    m.add_function(wrap_pyfunction!(_date_diff_epoch, m)?)?;
-    m.add_function(wrap_pyfunction!(_date_diff_as_days, m)?)?;
-    m.add_function(wrap_pyfunction!(_date_add_days, m)?)?;
-    m.add_function(wrap_pyfunction!(_date_add_months, m)?)?;
-    m.add_function(wrap_pyfunction!(_date_add_month, m)?)?;
-    m.add_function(wrap_pyfunction!(_date_add_ordinal, m)?)?;
-    m.add_function(wrap_pyfunction!(_date_add_year, m)?)?;
-    m.add_function(wrap_pyfunction!(_date_new, m)?)?;
+    m.add_function(wrap_pyfunction!(jelal_date_to_jtm, m)?)?;
+    #[cfg(feature = "c")]
+    m.add_function(wrap_pyfunction!(jelal_date_update_jtm, m)?)?;
+    m.add_function(wrap_pyfunction!(jelal_date_gregorian_ordinal, m)?)?;
+    m.add_function(wrap_pyfunction!(jelal_date_from_gregorian, m)?)?;
+    m.add_function(wrap_pyfunction!(jelal_date_month_progress_permille, m)?)?;
+    m.add_function(wrap_pyfunction!(jelal_date_days_in_year, m)?)?;
+    m.add_function(wrap_pyfunction!(jelal_date_is_leap_year, m)?)?;
+    m.add_function(wrap_pyfunction!(jelal_date_month_start_weekday, m)?)?;
+    m.add_function(wrap_pyfunction!(jelal_date_days_in_month, m)?)?;
+    m.add_function(wrap_pyfunction!(jelal_date_week_of_year, m)?)?;
+    m.add_function(wrap_pyfunction!(jelal_date_day_of_year, m)?)?;
+    m.add_function(wrap_pyfunction!(jelal_date_round_to_week, m)?)?;
+    m.add_function(wrap_pyfunction!(jelal_date_start_of_week, m)?)?;
+    m.add_function(wrap_pyfunction!(jelal_date_is_weekend_two_day, m)?)?;
+    m.add_function(wrap_pyfunction!(jelal_date_is_weekend, m)?)?;
+    m.add_function(wrap_pyfunction!(jelal_date_season, m)?)?;
+    m.add_function(wrap_pyfunction!(jelal_date_weekday, m)?)?;
+    m.add_function(wrap_pyfunction!(jelal_date_max_display_len, m)?)?;
+    m.add_function(wrap_pyfunction!(jelal_date_from_sort_key, m)?)?;
+    m.add_function(wrap_pyfunction!(jelal_date_to_sort_key, m)?)?;
+    m.add_function(wrap_pyfunction!(jelal_date_with_day, m)?)?;
+    m.add_function(wrap_pyfunction!(jelal_date_with_month, m)?)?;
+    m.add_function(wrap_pyfunction!(jelal_date_with_year, m)?)?;
+    m.add_function(wrap_pyfunction!(jelal_date_ordinal, m)?)?;
+    m.add_function(wrap_pyfunction!(jelal_date_year, m)?)?;
+    m.add_function(wrap_pyfunction!(jelal_date_days_until, m)?)?;
+    m.add_function(wrap_pyfunction!(jelal_date_last_day_of_month, m)?)?;
+    m.add_function(wrap_pyfunction!(jelal_date_first_day_of_month, m)?)?;
+    m.add_function(wrap_pyfunction!(jelal_date_is_consecutive_year, m)?)?;
+    m.add_function(wrap_pyfunction!(jelal_date_is_consecutive_month, m)?)?;
+    m.add_function(wrap_pyfunction!(jelal_date_is_consecutive_day, m)?)?;
+    m.add_function(wrap_pyfunction!(jelal_date_diff_as_months_wide, m)?)?;
+    m.add_function(wrap_pyfunction!(jelal_date_age_in_years, m)?)?;
+    m.add_function(wrap_pyfunction!(jelal_date_diff_as_years, m)?)?;
+    m.add_function(wrap_pyfunction!(jelal_date_diff_as_months, m)?)?;
+    m.add_function(wrap_pyfunction!(jelal_date_month_add_would_clamp, m)?)?;
+    m.add_function(wrap_pyfunction!(jelal_date_to_epoch_days, m)?)?;
+    m.add_function(wrap_pyfunction!(jelal_date_diff_epoch, m)?)?;
+    m.add_function(wrap_pyfunction!(jelal_date_diff_as_days, m)?)?;
+    m.add_function(wrap_pyfunction!(jelal_date_from_unix_timestamp, m)?)?;
+    m.add_function(wrap_pyfunction!(jelal_date_today_from_epoch_day, m)?)?;
+    m.add_function(wrap_pyfunction!(jelal_date_add_weeks, m)?)?;
+    m.add_function(wrap_pyfunction!(jelal_date_add_days, m)?)?;
+    m.add_function(wrap_pyfunction!(jelal_date_add_months, m)?)?;
+    m.add_function(wrap_pyfunction!(jelal_date_add_month, m)?)?;
+    m.add_function(wrap_pyfunction!(jelal_date_add_ordinal, m)?)?;
+    m.add_function(wrap_pyfunction!(jelal_date_add_year, m)?)?;
+    m.add_function(wrap_pyfunction!(jelal_date_new, m)?)?;
+    m.add_function(wrap_pyfunction!(jelal_date_is_reliable, m)?)?;
     m.add_class::<Date>()?;
-    m.add_function(wrap_pyfunction!(_monthday_ext_from_date, m)?)?;
-    m.add_function(wrap_pyfunction!(_monthday_ext_from_ordinal, m)?)?;
-    m.add_function(wrap_pyfunction!(_monthday_ext_cmp, m)?)?;
-    m.add_function(wrap_pyfunction!(_monthday_cmp, m)?)?;
-    m.add_function(wrap_pyfunction!(_monthday_day, m)?)?;
-    m.add_function(wrap_pyfunction!(_monthday_month, m)?)?;
-    m.add_function(wrap_pyfunction!(_monthday_from_ordinal, m)?)?;
-    m.add_function(wrap_pyfunction!(_monthday_add_day, m)?)?;
-    m.add_function(wrap_pyfunction!(_monthday_add_month, m)?)?;
-    m.add_function(wrap_pyfunction!(_monthday_to_ordinal, m)?)?;
-    m.add_function(wrap_pyfunction!(_monthday_new, m)?)?;
+    m.add_function(wrap_pyfunction!(jelal_monthday_ext_from_date, m)?)?;
+    m.add_function(wrap_pyfunction!(jelal_monthday_ext_from_ordinal, m)?)?;
+    m.add_function(wrap_pyfunction!(jelal_monthday_ext_cmp, m)?)?;
+    m.add_function(wrap_pyfunction!(jelal_monthday_cmp, m)?)?;
+    m.add_function(wrap_pyfunction!(jelal_monthday_day, m)?)?;
+    m.add_function(wrap_pyfunction!(jelal_monthday_month, m)?)?;
+    m.add_function(wrap_pyfunction!(jelal_monthday_from_ordinal, m)?)?;
+    m.add_function(wrap_pyfunction!(jelal_monthday_add_day, m)?)?;
+    m.add_function(wrap_pyfunction!(jelal_monthday_add_month, m)?)?;
+    m.add_function(wrap_pyfunction!(jelal_monthday_to_ordinal, m)?)?;
+    m.add_function(wrap_pyfunction!(jelal_monthday_new, m)?)?;
     m.add_class::<MonthDay>()?;
     Ok(())
 }
+#[cfg(feature = "py")]
+#[pymethods]
+impl MonthDay {
+    #[pyo3(name = "__lt__")]
+    fn __py_only_ext_lt(&self, other: &Self) -> bool {
+        self.ext_cmp(other) < 0
+    }
+    #[pyo3(name = "__le__")]
+    fn __py_only_ext_le(&self, other: &Self) -> bool {
+        self.ext_cmp(other) <= 0
+    }
+    #[pyo3(name = "__gt__")]
+    fn __py_only_ext_gt(&self, other: &Self) -> bool {
+        self.ext_cmp(other) > 0
+    }
+    #[pyo3(name = "__ge__")]
+    fn __py_only_ext_ge(&self, other: &Self) -> bool {
+        self.ext_cmp(other) >= 0
+    }
+    #[pyo3(name = "__eq__")]
+    fn __py_only_ext_eq(&self, other: &Self) -> bool {
+        crate::MonthDay::from(self.clone()) == crate::MonthDay::from(other.clone())
+    }
+    #[pyo3(name = "__hash__")]
+    fn __py_only_ext_hash(&self) -> u64 {
+        let mut hasher = ::std::hash::DefaultHasher::new();
+        ::core::hash::Hash::hash(&crate::MonthDay::from(self.clone()), &mut hasher);
+        ::core::hash::Hasher::finish(&hasher)
+    }
+}
+#[cfg(feature = "py")]
+#[pymethods]
+impl Date {
+    #[pyo3(name = "__lt__")]
+    fn __py_only_ext_lt(&self, other: &Self) -> bool {
+        self.ext_cmp(other) < 0
+    }
+    #[pyo3(name = "__le__")]
+    fn __py_only_ext_le(&self, other: &Self) -> bool {
+        self.ext_cmp(other) <= 0
+    }
+    #[pyo3(name = "__gt__")]
+    fn __py_only_ext_gt(&self, other: &Self) -> bool {
+        self.ext_cmp(other) > 0
+    }
+    #[pyo3(name = "__ge__")]
+    fn __py_only_ext_ge(&self, other: &Self) -> bool {
+        self.ext_cmp(other) >= 0
+    }
+    #[pyo3(name = "__eq__")]
+    fn __py_only_ext_eq(&self, other: &Self) -> bool {
+        crate::Date::from(self.clone()) == crate::Date::from(other.clone())
+    }
+    #[pyo3(name = "__hash__")]
+    fn __py_only_ext_hash(&self) -> u64 {
+        let mut hasher = ::std::hash::DefaultHasher::new();
+        ::core::hash::Hash::hash(&crate::Date::from(self.clone()), &mut hasher);
+        ::core::hash::Hasher::finish(&hasher)
+    }
+}
 impl From<MonthDay> for crate::MonthDay {
     fn from(value: MonthDay) -> Self {
         Self {
@@ -376,7 +779,7 @@ pub extern "C" fn monthday_new(month: UMonth, day: UMonthDay) -> MonthDay {
 #[doc = " Create a new valid instance and slightly saturate and modify to fit a valid instance."]
 #[cfg_attr(feature = "py", pyfunction)]
 #[cfg_attr(feature = "wasm", wasm_bindgen)]
-pub fn _monthday_new(month: UMonth, day: UMonthDay) -> MonthDay {
+pub fn jelal_monthday_new(month: UMonth, day: UMonthDay) -> MonthDay {
     MonthDay::new(month.into(), day.into()).into()
 }
 #[doc = " Return the ordinal (day of the year) for this month and its day."]
@@ -388,7 +791,7 @@ pub extern "C" fn monthday_to_ordinal(this: &MonthDay) -> UOrdinal {
 #[doc = " Return the ordinal (day of the year) for this month and its day."]
 #[cfg_attr(feature = "py", pyfunction)]
 #[cfg_attr(feature = "wasm", wasm_bindgen)]
-pub fn _monthday_to_ordinal(this: &MonthDay) -> UOrdinal {
+pub fn jelal_monthday_to_ordinal(this: &MonthDay) -> UOrdinal {
     MonthDay::to_ordinal(&this.clone().into()).into()
 }
 #[doc = " Add or sub a value to this month and saturate to the limits.\n\n This is exactly as [`Self::add_month_strict`] but returns the value only."]
@@ -400,7 +803,7 @@ pub extern "C" fn monthday_add_month(this: MonthDay, month: IMonth) -> MonthDay
 #[doc = " Add or sub a value to this month and saturate to the limits.\n\n This is exactly as [`Self::add_month_strict`] but returns the value only."]
 #[cfg_attr(feature = "py", pyfunction)]
 #[cfg_attr(feature = "wasm", wasm_bindgen)]
-pub fn _monthday_add_month(this: MonthDay, month: IMonth) -> MonthDay {
+pub fn jelal_monthday_add_month(this: MonthDay, month: IMonth) -> MonthDay {
     MonthDay::add_month(this.into(), month.into()).into()
 }
 #[doc = " Add or sub a value to the day of this and saturate to the limits.\n\n This is exactly as [`Self::add_day_strict`] but returns the value only."]
@@ -412,7 +815,7 @@ pub extern "C" fn monthday_add_day(this: MonthDay, day: IMonthDay) -> MonthDay {
 #[doc = " Add or sub a value to the day of this and saturate to the limits.\n\n This is exactly as [`Self::add_day_strict`] but returns the value only."]
 #[cfg_attr(feature = "py", pyfunction)]
 #[cfg_attr(feature = "wasm", wasm_bindgen)]
-pub fn _monthday_add_day(this: MonthDay, day: IMonthDay) -> MonthDay {
+pub fn jelal_monthday_add_day(this: MonthDay, day: IMonthDay) -> MonthDay {
     MonthDay::add_day(this.into(), day.into()).into()
 }
 #[doc = " Create a valid month and day (in order) from a valid day of the year."]
@@ -424,7 +827,7 @@ pub extern "C" fn monthday_from_ordinal(value: UOrdinal) -> MonthDay {
 #[doc = " Create a valid month and day (in order) from a valid day of the year."]
 #[cfg_attr(feature = "py", pyfunction)]
 #[cfg_attr(feature = "wasm", wasm_bindgen)]
-pub fn _monthday_from_ordinal(value: UOrdinal) -> MonthDay {
+pub fn jelal_monthday_from_ordinal(value: UOrdinal) -> MonthDay {
     MonthDay::from_ordinal(value.into()).into()
 }
 #[doc = " Return the value of inner `Self::month` for this instance."]
@@ -436,7 +839,7 @@ pub extern "C" fn monthday_month(this: &MonthDay) -> UMonth {
 #[doc = " Return the value of inner `Self::month` for this instance."]
 #[cfg_attr(feature = "py", pyfunction)]
 #[cfg_attr(feature = "wasm", wasm_bindgen)]
-pub fn _monthday_month(this: &MonthDay) -> UMonth {
+pub fn jelal_monthday_month(this: &MonthDay) -> UMonth {
     MonthDay::month(&this.clone().into()).into()
 }
 #[doc = " Return the value of inner `Self::day` for this instance."]
@@ -448,7 +851,7 @@ pub extern "C" fn monthday_day(this: &MonthDay) -> UMonthDay {
 #[doc = " Return the value of inner `Self::day` for this instance."]
 #[cfg_attr(feature = "py", pyfunction)]
 #[cfg_attr(feature = "wasm", wasm_bindgen)]
-pub fn _monthday_day(this: &MonthDay) -> UMonthDay {
+pub fn jelal_monthday_day(this: &MonthDay) -> UMonthDay {
     MonthDay::day(&this.clone().into()).into()
 }
 #[doc = " Const-context definition of [`Ord::cmp`]."]
@@ -460,7 +863,7 @@ pub extern "C" fn monthday_cmp(this: &MonthDay, other: &MonthDay) -> Ordering {
 #[doc = " Const-context definition of [`Ord::cmp`]."]
 #[cfg_attr(feature = "py", pyfunction)]
 #[cfg_attr(feature = "wasm", wasm_bindgen)]
-pub fn _monthday_cmp(this: &MonthDay, other: &MonthDay) -> Ordering {
+pub fn jelal_monthday_cmp(this: &MonthDay, other: &MonthDay) -> Ordering {
     MonthDay::cmp(&this.clone().into(), &other.clone().into()).into()
 }
 #[cfg_attr(feature = "py", pymethods)]
@@ -565,7 +968,7 @@ pub extern "C" fn monthday_ext_cmp(this: &MonthDay, other: &MonthDay) -> i8 {
 }
 #[cfg_attr(feature = "py", pyfunction)]
 #[cfg_attr(feature = "wasm", wasm_bindgen)]
-pub fn _monthday_ext_cmp(this: &MonthDay, other: &MonthDay) -> i8 {
+pub fn jelal_monthday_ext_cmp(this: &MonthDay, other: &MonthDay) -> i8 {
     MonthDay::ext_cmp(&this.clone().into(), &other.clone().into()).into()
 }
 #[cfg_attr(feature = "wasm", wasm_bindgen)]
@@ -575,6 +978,25 @@ impl MonthDay {
         crate::MonthDay::from(self.clone()).cmp(&crate::MonthDay::from(other.clone())) as i8
     }
 }
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+impl MonthDay {
+    #[doc = " FFI version of an `Ord` comparison operator"]
+    pub fn ext_lt(&self, other: &Self) -> bool {
+        self.ext_cmp(other) < 0
+    }
+    #[doc = " FFI version of an `Ord` comparison operator"]
+    pub fn ext_le(&self, other: &Self) -> bool {
+        self.ext_cmp(other) <= 0
+    }
+    #[doc = " FFI version of an `Ord` comparison operator"]
+    pub fn ext_gt(&self, other: &Self) -> bool {
+        self.ext_cmp(other) > 0
+    }
+    #[doc = " FFI version of an `Ord` comparison operator"]
+    pub fn ext_ge(&self, other: &Self) -> bool {
+        self.ext_cmp(other) >= 0
+    }
+}
 #[doc = " FFI version of a `From` trait implementation"]
 #[cfg(feature = "c")]
 #[unsafe(no_mangle)]
@@ -584,7 +1006,7 @@ pub extern "C" fn monthday_ext_from_ordinal(value: UOrdinal) -> MonthDay {
 #[doc = " FFI version of a `From` trait implementation"]
 #[cfg_attr(feature = "py", pyfunction)]
 #[cfg_attr(feature = "wasm", wasm_bindgen)]
-pub fn _monthday_ext_from_ordinal(value: UOrdinal) -> MonthDay {
+pub fn jelal_monthday_ext_from_ordinal(value: UOrdinal) -> MonthDay {
     MonthDay::ext_from_ordinal(value.into()).into()
 }
 #[cfg(feature = "py")]
@@ -614,7 +1036,7 @@ pub extern "C" fn monthday_ext_from_date(value: Date) -> MonthDay {
 #[doc = " FFI version of a `From` trait implementation"]
 #[cfg_attr(feature = "py", pyfunction)]
 #[cfg_attr(feature = "wasm", wasm_bindgen)]
-pub fn _monthday_ext_from_date(value: Date) -> MonthDay {
+pub fn jelal_monthday_ext_from_date(value: Date) -> MonthDay {
     MonthDay::ext_from_date(value.into()).into()
 }
 #[cfg(feature = "py")]
@@ -662,7 +1084,7 @@ pub static _DATE_MIN: Date = DATE_MIN;
 #[doc = " The furthest in the future that can be represented with this struct."]
 pub const DATE_MAX: Date = Date {
     year: Year::MAX,
-    ordinal: Ordinal::MAX,
+    ordinal: Ordinal::MAX_NON_LEAP,
 };
 #[doc = " The furthest in the future that can be represented with this struct."]
 #[unsafe(export_name = "DATE_MAX")]
@@ -675,6 +1097,34 @@ pub const DATE_EPOCH: Date = Date {
 #[doc = " Unix Epoch in this format (equivalent to Gregorian 1st of January [`MonthDay`], 1970)."]
 #[unsafe(export_name = "DATE_EPOCH")]
 pub static _DATE_EPOCH: Date = DATE_EPOCH;
+#[doc = " The furthest in the past that its leap years are calculated astronomically."]
+pub const DATE_RELIABLE_MIN: Date = Date {
+    year: Year::ASTRONOMICAL_MIN,
+    ordinal: Ordinal::MIN,
+};
+#[doc = " The furthest in the past that its leap years are calculated astronomically."]
+#[unsafe(export_name = "DATE_RELIABLE_MIN")]
+pub static _DATE_RELIABLE_MIN: Date = DATE_RELIABLE_MIN;
+#[doc = " The furthest in the future that its leap years are calculated astronomically.\n\n [`Year::ASTRONOMICAL_MAX`] is not a leap year."]
+pub const DATE_RELIABLE_MAX: Date = Date {
+    year: Year::ASTRONOMICAL_MAX,
+    ordinal: Ordinal::MAX_NON_LEAP,
+};
+#[doc = " The furthest in the future that its leap years are calculated astronomically.\n\n [`Year::ASTRONOMICAL_MAX`] is not a leap year."]
+#[unsafe(export_name = "DATE_RELIABLE_MAX")]
+pub static _DATE_RELIABLE_MAX: Date = DATE_RELIABLE_MAX;
+#[doc = " Is this date between [`Self::RELIABLE_MIN`] and [`Self::RELIABLE_MAX`].\n\n Outside this range the leap years fall back to the 33-year rule (see [`Year::is_leap`]) so\n conversions and day counts may differ from the real calendar."]
+#[cfg(feature = "c")]
+#[unsafe(no_mangle)]
+pub extern "C" fn date_is_reliable(this: &Date) -> bool {
+    Date::is_reliable(&this.clone().into()).into()
+}
+#[doc = " Is this date between [`Self::RELIABLE_MIN`] and [`Self::RELIABLE_MAX`].\n\n Outside this range the leap years fall back to the 33-year rule (see [`Year::is_leap`]) so\n conversions and day counts may differ from the real calendar."]
+#[cfg_attr(feature = "py", pyfunction)]
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+pub fn jelal_date_is_reliable(this: &Date) -> bool {
+    Date::is_reliable(&this.clone().into()).into()
+}
 #[doc = " Create a new Jalali date or slightly change values to be valid."]
 #[cfg(feature = "c")]
 #[unsafe(no_mangle)]
@@ -684,7 +1134,7 @@ pub extern "C" fn date_new(year: IYear, ordinal: UOrdinal) -> Date {
 #[doc = " Create a new Jalali date or slightly change values to be valid."]
 #[cfg_attr(feature = "py", pyfunction)]
 #[cfg_attr(feature = "wasm", wasm_bindgen)]
-pub fn _date_new(year: IYear, ordinal: UOrdinal) -> Date {
+pub fn jelal_date_new(year: IYear, ordinal: UOrdinal) -> Date {
     Date::new(year.into(), ordinal.into()).into()
 }
 #[doc = " Add a year to this date and saturate the results at limits.\n\n This is exactly as [`Self::add_year_strict`] but returns the value only."]
@@ -696,7 +1146,7 @@ pub extern "C" fn date_add_year(this: Date, year: IYear) -> Date {
 #[doc = " Add a year to this date and saturate the results at limits.\n\n This is exactly as [`Self::add_year_strict`] but returns the value only."]
 #[cfg_attr(feature = "py", pyfunction)]
 #[cfg_attr(feature = "wasm", wasm_bindgen)]
-pub fn _date_add_year(this: Date, year: IYear) -> Date {
+pub fn jelal_date_add_year(this: Date, year: IYear) -> Date {
     Date::add_year(this.into(), year.into()).into()
 }
 #[doc = " Add a ordinal to this date and saturate the results at limits.\n\n This is exactly as [`Self::add_ordinal_strict`] but returns the value only."]
@@ -708,7 +1158,7 @@ pub extern "C" fn date_add_ordinal(this: Date, ordinal: IOrdinal) -> Date {
 #[doc = " Add a ordinal to this date and saturate the results at limits.\n\n This is exactly as [`Self::add_ordinal_strict`] but returns the value only."]
 #[cfg_attr(feature = "py", pyfunction)]
 #[cfg_attr(feature = "wasm", wasm_bindgen)]
-pub fn _date_add_ordinal(this: Date, ordinal: IOrdinal) -> Date {
+pub fn jelal_date_add_ordinal(this: Date, ordinal: IOrdinal) -> Date {
     Date::add_ordinal(this.into(), ordinal.into()).into()
 }
 #[doc = " Add a month count to this date and saturate the results at limits.\n\n This is exactly as [`Self::add_month_strict`] but returns the value only."]
@@ -720,7 +1170,7 @@ pub extern "C" fn date_add_month(this: Date, month: IMonth) -> Date {
 #[doc = " Add a month count to this date and saturate the results at limits.\n\n This is exactly as [`Self::add_month_strict`] but returns the value only."]
 #[cfg_attr(feature = "py", pyfunction)]
 #[cfg_attr(feature = "wasm", wasm_bindgen)]
-pub fn _date_add_month(this: Date, month: IMonth) -> Date {
+pub fn jelal_date_add_month(this: Date, month: IMonth) -> Date {
     Date::add_month(this.into(), month.into()).into()
 }
 #[doc = " Add this many consecutive months to this date.\n\n This is exactly as [`Self::add_months_strict`] but returns the value only."]
@@ -732,7 +1182,7 @@ pub extern "C" fn date_add_months(this: Date, months: IDayDiff) -> Date {
 #[doc = " Add this many consecutive months to this date.\n\n This is exactly as [`Self::add_months_strict`] but returns the value only."]
 #[cfg_attr(feature = "py", pyfunction)]
 #[cfg_attr(feature = "wasm", wasm_bindgen)]
-pub fn _date_add_months(this: Date, months: IDayDiff) -> Date {
+pub fn jelal_date_add_months(this: Date, months: IDayDiff) -> Date {
     Date::add_months(this.into(), months.into()).into()
 }
 #[doc = " Add or remove the given number of consecutive days to this date.\n\n This is exactly as [`Self::add_days_strict`] but returns the value only."]
@@ -744,9 +1194,45 @@ pub extern "C" fn date_add_days(this: Date, days: IDayDiff) -> Date {
 #[doc = " Add or remove the given number of consecutive days to this date.\n\n This is exactly as [`Self::add_days_strict`] but returns the value only."]
 #[cfg_attr(feature = "py", pyfunction)]
 #[cfg_attr(feature = "wasm", wasm_bindgen)]
-pub fn _date_add_days(this: Date, days: IDayDiff) -> Date {
+pub fn jelal_date_add_days(this: Date, days: IDayDiff) -> Date {
     Date::add_days(this.into(), days.into()).into()
 }
+#[doc = " Add or remove the given number of weeks (7 days each) to this date.\n\n This is exactly as [`Self::add_weeks_strict`] but returns the value only."]
+#[cfg(feature = "c")]
+#[unsafe(no_mangle)]
+pub extern "C" fn date_add_weeks(this: Date, weeks: IDayDiff) -> Date {
+    Date::add_weeks(this.into(), weeks.into()).into()
+}
+#[doc = " Add or remove the given number of weeks (7 days each) to this date.\n\n This is exactly as [`Self::add_weeks_strict`] but returns the value only."]
+#[cfg_attr(feature = "py", pyfunction)]
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+pub fn jelal_date_add_weeks(this: Date, weeks: IDayDiff) -> Date {
+    Date::add_weeks(this.into(), weeks.into()).into()
+}
+#[doc = " Return the date of \"today\" given how many days are passed since Unix Epoch ([`Self::EPOCH`]).\n\n This is a pure alternative of reading the clock, so the code depending on the date of today\n can be tested deterministically by injecting the day count instead of mocking the clock."]
+#[cfg(feature = "c")]
+#[unsafe(no_mangle)]
+pub extern "C" fn date_today_from_epoch_day(epoch_day: IDayDiff) -> Date {
+    Date::today_from_epoch_day(epoch_day.into()).into()
+}
+#[doc = " Return the date of \"today\" given how many days are passed since Unix Epoch ([`Self::EPOCH`]).\n\n This is a pure alternative of reading the clock, so the code depending on the date of today\n can be tested deterministically by injecting the day count instead of mocking the clock."]
+#[cfg_attr(feature = "py", pyfunction)]
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+pub fn jelal_date_today_from_epoch_day(epoch_day: IDayDiff) -> Date {
+    Date::today_from_epoch_day(epoch_day.into()).into()
+}
+#[doc = " Return the date (in UTC) of the given Unix timestamp (seconds after Unix Epoch).\n\n Negative seconds are before the epoch and any part of a day is counted as that whole day\n (e.g. -1 is 1969-12-31). See [`Self::from_epoch_days`]."]
+#[cfg(feature = "c")]
+#[unsafe(no_mangle)]
+pub extern "C" fn date_from_unix_timestamp(secs: i64) -> Date {
+    Date::from_unix_timestamp(secs.into()).into()
+}
+#[doc = " Return the date (in UTC) of the given Unix timestamp (seconds after Unix Epoch).\n\n Negative seconds are before the epoch and any part of a day is counted as that whole day\n (e.g. -1 is 1969-12-31). See [`Self::from_epoch_days`]."]
+#[cfg_attr(feature = "py", pyfunction)]
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+pub fn jelal_date_from_unix_timestamp(secs: i64) -> Date {
+    Date::from_unix_timestamp(secs.into()).into()
+}
 #[doc = " Return how many days on this date will result to the given destination.\n\n This is exactly as [`Self::diff_as_days_strict`] but returns the value only."]
 #[cfg(feature = "c")]
 #[unsafe(no_mangle)]
@@ -756,7 +1242,7 @@ pub extern "C" fn date_diff_as_days(this: &Date, other: Date) -> IDayDiff {
 #[doc = " Return how many days on this date will result to the given destination.\n\n This is exactly as [`Self::diff_as_days_strict`] but returns the value only."]
 #[cfg_attr(feature = "py", pyfunction)]
 #[cfg_attr(feature = "wasm", wasm_bindgen)]
-pub fn _date_diff_as_days(this: &Date, other: Date) -> IDayDiff {
+pub fn jelal_date_diff_as_days(this: &Date, other: Date) -> IDayDiff {
     Date::diff_as_days(&this.clone().into(), other.into()).into()
 }
 #[doc = " Return how many days has passed since or is yet to reach [`Self::EPOCH`].\n\n This is exactly as [`Self::diff_epoch_strict`] but returns the value only."]
@@ -768,122 +1254,686 @@ pub extern "C" fn date_diff_epoch(this: &Date) -> IDayDiff {
 #[doc = " Return how many days has passed since or is yet to reach [`Self::EPOCH`].\n\n This is exactly as [`Self::diff_epoch_strict`] but returns the value only."]
 #[cfg_attr(feature = "py", pyfunction)]
 #[cfg_attr(feature = "wasm", wasm_bindgen)]
-pub fn _date_diff_epoch(this: &Date) -> IDayDiff {
+pub fn jelal_date_diff_epoch(this: &Date) -> IDayDiff {
     Date::diff_epoch(&this.clone().into()).into()
 }
-#[doc = " Return the value of inner `Self::year` for this instance."]
+#[doc = " Return the number of days since Unix Epoch ([`Self::EPOCH`]) as a continuous day index.\n\n The later dates have larger numbers: the epoch is 0, the day after is 1 and 1969-12-31 is\n -1. This is the reverse of [`Self::from_epoch_days`] and the same as [`Self::diff_epoch`]."]
 #[cfg(feature = "c")]
 #[unsafe(no_mangle)]
-pub extern "C" fn date_year(this: &Date) -> IYear {
-    Date::year(&this.clone().into()).into()
+pub extern "C" fn date_to_epoch_days(this: &Date) -> IDayDiff {
+    Date::to_epoch_days(&this.clone().into()).into()
 }
-#[doc = " Return the value of inner `Self::year` for this instance."]
+#[doc = " Return the number of days since Unix Epoch ([`Self::EPOCH`]) as a continuous day index.\n\n The later dates have larger numbers: the epoch is 0, the day after is 1 and 1969-12-31 is\n -1. This is the reverse of [`Self::from_epoch_days`] and the same as [`Self::diff_epoch`]."]
 #[cfg_attr(feature = "py", pyfunction)]
 #[cfg_attr(feature = "wasm", wasm_bindgen)]
-pub fn _date_year(this: &Date) -> IYear {
-    Date::year(&this.clone().into()).into()
+pub fn jelal_date_to_epoch_days(this: &Date) -> IDayDiff {
+    Date::to_epoch_days(&this.clone().into()).into()
 }
-#[doc = " Return the value of inner `Self::ordinal` for this instance."]
+#[doc = " Return if the day of this date does not exist after adding the given months.\n\n This is true when the day is greater than the length of the target month (e.g. 31st of\n any month moving into a 30 day month), see [`Self::add_months`]."]
 #[cfg(feature = "c")]
 #[unsafe(no_mangle)]
-pub extern "C" fn date_ordinal(this: &Date) -> UOrdinal {
-    Date::ordinal(&this.clone().into()).into()
+pub extern "C" fn date_month_add_would_clamp(this: &Date, months: IDayDiff) -> bool {
+    Date::month_add_would_clamp(&this.clone().into(), months.into()).into()
 }
-#[doc = " Return the value of inner `Self::ordinal` for this instance."]
+#[doc = " Return if the day of this date does not exist after adding the given months.\n\n This is true when the day is greater than the length of the target month (e.g. 31st of\n any month moving into a 30 day month), see [`Self::add_months`]."]
 #[cfg_attr(feature = "py", pyfunction)]
 #[cfg_attr(feature = "wasm", wasm_bindgen)]
-pub fn _date_ordinal(this: &Date) -> UOrdinal {
-    Date::ordinal(&this.clone().into()).into()
+pub fn jelal_date_month_add_would_clamp(this: &Date, months: IDayDiff) -> bool {
+    Date::month_add_would_clamp(&this.clone().into(), months.into()).into()
 }
-#[doc = " Convert this [`Self::to_jtm`] but on the given struct."]
+#[doc = " Return how many whole months on this date will result to the given destination.\n\n This is exactly as [`Self::diff_as_months_strict`] but returns the value only."]
 #[cfg(feature = "c")]
+#[unsafe(no_mangle)]
+pub extern "C" fn date_diff_as_months(this: &Date, other: Date) -> IDayDiff {
+    Date::diff_as_months(&this.clone().into(), other.into()).into()
+}
+#[doc = " Return how many whole months on this date will result to the given destination.\n\n This is exactly as [`Self::diff_as_months_strict`] but returns the value only."]
+#[cfg_attr(feature = "py", pyfunction)]
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+pub fn jelal_date_diff_as_months(this: &Date, other: Date) -> IDayDiff {
+    Date::diff_as_months(&this.clone().into(), other.into()).into()
+}
+#[doc = " Return how many whole years on this date will result to the given destination.\n\n This is exactly as [`Self::diff_as_years_strict`] but returns the value only."]
 #[cfg(feature = "c")]
 #[unsafe(no_mangle)]
-pub extern "C" fn date_update_jtm(this: &Date, jtm: &mut tm) {
-    Date::update_jtm(&this.clone().into(), &mut jtm.clone().into()).into()
+pub extern "C" fn date_diff_as_years(this: &Date, other: Date) -> IDayDiff {
+    Date::diff_as_years(&this.clone().into(), other.into()).into()
 }
-#[doc = " Convert this [`Self::to_jtm`] but on the given struct."]
+#[doc = " Return how many whole years on this date will result to the given destination.\n\n This is exactly as [`Self::diff_as_years_strict`] but returns the value only."]
+#[cfg_attr(feature = "py", pyfunction)]
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+pub fn jelal_date_diff_as_years(this: &Date, other: Date) -> IDayDiff {
+    Date::diff_as_years(&this.clone().into(), other.into()).into()
+}
+#[doc = " Return the completed years from this date (e.g. a birthdate) to `as_of` (i.e. the age).\n\n The birthday on the 30th of [`Month::MAX`] is on its 29th in non-leap years. The result is\n negative if `as_of` is earlier. This is exactly as `as_of.diff_as_years(self)` (see\n [`Self::diff_as_years`])."]
 #[cfg(feature = "c")]
+#[unsafe(no_mangle)]
+pub extern "C" fn date_age_in_years(this: &Date, as_of: Date) -> IDayDiff {
+    Date::age_in_years(&this.clone().into(), as_of.into()).into()
+}
+#[doc = " Return the completed years from this date (e.g. a birthdate) to `as_of` (i.e. the age).\n\n The birthday on the 30th of [`Month::MAX`] is on its 29th in non-leap years. The result is\n negative if `as_of` is earlier. This is exactly as `as_of.diff_as_years(self)` (see\n [`Self::diff_as_years`])."]
 #[cfg_attr(feature = "py", pyfunction)]
 #[cfg_attr(feature = "wasm", wasm_bindgen)]
-pub fn _date_update_jtm(this: &Date, jtm: &mut tm) {
-    Date::update_jtm(&this.clone().into(), &mut jtm.clone().into()).into()
+pub fn jelal_date_age_in_years(this: &Date, as_of: Date) -> IDayDiff {
+    Date::age_in_years(&this.clone().into(), as_of.into()).into()
 }
-#[doc = " Create an [`ffi::tm`] from this date in Jalali.\n\n If the aim is not to create a new instance and update an already created `tm`, use\n [`Self::update_jtm`].\n\n See its documents for how this struct's values should be interpreted when the date is\n assumed to be Jalali. In short, this is exactly as in C but year doesn't have an offset and\n only year, month, ordinal and month day are set.\n\n There are no `from_jtm` equal since there are many ways interprete how this should be done,\n (based on ordinal `yday` or `year`, `mon`, `mday` fields to name two).\n\n To convert this value into a `tm` (Gregorian) use [`Self::diff_epoch`] and then convert that\n to seconds to use with `localtime` and `gmtime`."]
+#[doc = " Return the whole months between the dates in a type which never overflows."]
 #[cfg(feature = "c")]
+#[unsafe(no_mangle)]
+extern "C" fn date_diff_as_months_wide(this: &Date, other: &Date) -> i64 {
+    Date::diff_as_months_wide(&this.clone().into(), &other.clone().into()).into()
+}
+#[doc = " Return the whole months between the dates in a type which never overflows."]
+#[cfg_attr(feature = "py", pyfunction)]
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+pub fn jelal_date_diff_as_months_wide(this: &Date, other: &Date) -> i64 {
+    Date::diff_as_months_wide(&this.clone().into(), &other.clone().into()).into()
+}
+#[doc = " Return if the given date is the day after or before this date."]
 #[cfg(feature = "c")]
 #[unsafe(no_mangle)]
-pub extern "C" fn date_to_jtm(this: &Date) -> tm {
-    Date::to_jtm(&this.clone().into()).into()
+pub extern "C" fn date_is_consecutive_day(this: &Date, other: &Date) -> bool {
+    Date::is_consecutive_day(&this.clone().into(), &other.clone().into()).into()
+}
+#[doc = " Return if the given date is the day after or before this date."]
+#[cfg_attr(feature = "py", pyfunction)]
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+pub fn jelal_date_is_consecutive_day(this: &Date, other: &Date) -> bool {
+    Date::is_consecutive_day(&this.clone().into(), &other.clone().into()).into()
 }
-#[doc = " Create an [`ffi::tm`] from this date in Jalali.\n\n If the aim is not to create a new instance and update an already created `tm`, use\n [`Self::update_jtm`].\n\n See its documents for how this struct's values should be interpreted when the date is\n assumed to be Jalali. In short, this is exactly as in C but year doesn't have an offset and\n only year, month, ordinal and month day are set.\n\n There are no `from_jtm` equal since there are many ways interprete how this should be done,\n (based on ordinal `yday` or `year`, `mon`, `mday` fields to name two).\n\n To convert this value into a `tm` (Gregorian) use [`Self::diff_epoch`] and then convert that\n to seconds to use with `localtime` and `gmtime`."]
+#[doc = " Return if the given date is in the month after or before the month of this date."]
 #[cfg(feature = "c")]
+#[unsafe(no_mangle)]
+pub extern "C" fn date_is_consecutive_month(this: &Date, other: &Date) -> bool {
+    Date::is_consecutive_month(&this.clone().into(), &other.clone().into()).into()
+}
+#[doc = " Return if the given date is in the month after or before the month of this date."]
 #[cfg_attr(feature = "py", pyfunction)]
 #[cfg_attr(feature = "wasm", wasm_bindgen)]
-pub fn _date_to_jtm(this: &Date) -> tm {
-    Date::to_jtm(&this.clone().into()).into()
+pub fn jelal_date_is_consecutive_month(this: &Date, other: &Date) -> bool {
+    Date::is_consecutive_month(&this.clone().into(), &other.clone().into()).into()
 }
-#[doc = " Const-context definition of [`Ord::cmp`]."]
+#[doc = " Return if the given date is in the year after or before the year of this date."]
 #[cfg(feature = "c")]
 #[unsafe(no_mangle)]
-pub extern "C" fn date_cmp(this: &Date, other: &Date) -> Ordering {
-    Date::cmp(&this.clone().into(), &other.clone().into()).into()
+pub extern "C" fn date_is_consecutive_year(this: &Date, other: &Date) -> bool {
+    Date::is_consecutive_year(&this.clone().into(), &other.clone().into()).into()
 }
-#[doc = " Const-context definition of [`Ord::cmp`]."]
+#[doc = " Return if the given date is in the year after or before the year of this date."]
 #[cfg_attr(feature = "py", pyfunction)]
 #[cfg_attr(feature = "wasm", wasm_bindgen)]
-pub fn _date_cmp(this: &Date, other: &Date) -> Ordering {
-    Date::cmp(&this.clone().into(), &other.clone().into()).into()
+pub fn jelal_date_is_consecutive_year(this: &Date, other: &Date) -> bool {
+    Date::is_consecutive_year(&this.clone().into(), &other.clone().into()).into()
 }
-#[cfg_attr(feature = "py", pymethods)]
-impl Date {
-    #[doc = " The furthest in the past that can be represented with this struct."]
-    pub const MIN: Self = Date {
-        year: Year::MIN,
-        ordinal: Ordinal::MIN,
-    };
-    #[doc = " The furthest in the future that can be represented with this struct."]
-    pub const MAX: Self = Date {
-        year: Year::MAX,
-        ordinal: Ordinal::MAX,
-    };
-    #[doc = " Unix Epoch in this format (equivalent to Gregorian 1st of January [`MonthDay`], 1970)."]
-    pub const EPOCH: Self = Date {
-        year: Year::EPOCH,
-        ordinal: Ordinal::EPOCH,
-    };
+#[doc = " Return the first day of the month of this date."]
+#[cfg(feature = "c")]
+#[unsafe(no_mangle)]
+pub extern "C" fn date_first_day_of_month(this: &Date) -> Date {
+    Date::first_day_of_month(&this.clone().into()).into()
 }
+#[doc = " Return the first day of the month of this date."]
+#[cfg_attr(feature = "py", pyfunction)]
 #[cfg_attr(feature = "wasm", wasm_bindgen)]
-impl Date {
-    #[doc = " Add a year to this date and saturate the results at limits.\n\n This is exactly as [`Self::add_year_strict`] but returns the value only."]
-    pub fn add_year(self, year: IYear) -> Date {
-        let this = self;
-        let this: crate::Date = this.into();
-        unsafe { ::core::mem::transmute(crate::Date::add_year(this, year.into())) }
-    }
-    #[doc = " Add a ordinal to this date and saturate the results at limits.\n\n This is exactly as [`Self::add_ordinal_strict`] but returns the value only."]
-    pub fn add_ordinal(self, ordinal: IOrdinal) -> Date {
-        let this = self;
-        let this: crate::Date = this.into();
-        unsafe { ::core::mem::transmute(crate::Date::add_ordinal(this, ordinal.into())) }
-    }
-    #[doc = " Add a month count to this date and saturate the results at limits.\n\n This is exactly as [`Self::add_month_strict`] but returns the value only."]
-    pub fn add_month(self, month: IMonth) -> Date {
-        let this = self;
-        let this: crate::Date = this.into();
-        unsafe { ::core::mem::transmute(crate::Date::add_month(this, month.into())) }
-    }
-    #[doc = " Add this many consecutive months to this date.\n\n This is exactly as [`Self::add_months_strict`] but returns the value only."]
-    pub fn add_months(self, months: IDayDiff) -> Date {
-        let this = self;
-        let this: crate::Date = this.into();
+pub fn jelal_date_first_day_of_month(this: &Date) -> Date {
+    Date::first_day_of_month(&this.clone().into()).into()
+}
+#[doc = " Return the last day of the month of this date (leap correct)."]
+#[cfg(feature = "c")]
+#[unsafe(no_mangle)]
+pub extern "C" fn date_last_day_of_month(this: &Date) -> Date {
+    Date::last_day_of_month(&this.clone().into()).into()
+}
+#[doc = " Return the last day of the month of this date (leap correct)."]
+#[cfg_attr(feature = "py", pyfunction)]
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+pub fn jelal_date_last_day_of_month(this: &Date) -> Date {
+    Date::last_day_of_month(&this.clone().into()).into()
+}
+#[doc = " Return how many days are left until the given date or 0 if it is today or in the past.\n\n This is an unsigned countdown unlike [`Self::diff_as_days`]."]
+#[cfg(feature = "c")]
+#[unsafe(no_mangle)]
+pub extern "C" fn date_days_until(this: &Date, target: Date) -> UDayDiff {
+    Date::days_until(&this.clone().into(), target.into()).into()
+}
+#[doc = " Return how many days are left until the given date or 0 if it is today or in the past.\n\n This is an unsigned countdown unlike [`Self::diff_as_days`]."]
+#[cfg_attr(feature = "py", pyfunction)]
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+pub fn jelal_date_days_until(this: &Date, target: Date) -> UDayDiff {
+    Date::days_until(&this.clone().into(), target.into()).into()
+}
+#[doc = " Return the value of inner `Self::year` for this instance."]
+#[cfg(feature = "c")]
+#[unsafe(no_mangle)]
+pub extern "C" fn date_year(this: &Date) -> IYear {
+    Date::year(&this.clone().into()).into()
+}
+#[doc = " Return the value of inner `Self::year` for this instance."]
+#[cfg_attr(feature = "py", pyfunction)]
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+pub fn jelal_date_year(this: &Date) -> IYear {
+    Date::year(&this.clone().into()).into()
+}
+#[doc = " Return the value of inner `Self::ordinal` for this instance."]
+#[cfg(feature = "c")]
+#[unsafe(no_mangle)]
+pub extern "C" fn date_ordinal(this: &Date) -> UOrdinal {
+    Date::ordinal(&this.clone().into()).into()
+}
+#[doc = " Return the value of inner `Self::ordinal` for this instance."]
+#[cfg_attr(feature = "py", pyfunction)]
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+pub fn jelal_date_ordinal(this: &Date) -> UOrdinal {
+    Date::ordinal(&this.clone().into()).into()
+}
+#[doc = " Return this date in the given year, keeping the month and the day (leap correct).\n\n The last day of a leap year is limited to the last day of the given year if not leap."]
+#[cfg(feature = "c")]
+#[unsafe(no_mangle)]
+pub extern "C" fn date_with_year(this: Date, year: IYear) -> Date {
+    Date::with_year(this.into(), year.into()).into()
+}
+#[doc = " Return this date in the given year, keeping the month and the day (leap correct).\n\n The last day of a leap year is limited to the last day of the given year if not leap."]
+#[cfg_attr(feature = "py", pyfunction)]
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+pub fn jelal_date_with_year(this: Date, year: IYear) -> Date {
+    Date::with_year(this.into(), year.into()).into()
+}
+#[doc = " Return this date in the given month, keeping the year and the day (leap correct).\n\n The day is limited to the last day of the given month (e.g. 31 on the 11th month is 30)."]
+#[cfg(feature = "c")]
+#[unsafe(no_mangle)]
+pub extern "C" fn date_with_month(this: Date, month: UMonth) -> Date {
+    Date::with_month(this.into(), month.into()).into()
+}
+#[doc = " Return this date in the given month, keeping the year and the day (leap correct).\n\n The day is limited to the last day of the given month (e.g. 31 on the 11th month is 30)."]
+#[cfg_attr(feature = "py", pyfunction)]
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+pub fn jelal_date_with_month(this: Date, month: UMonth) -> Date {
+    Date::with_month(this.into(), month.into()).into()
+}
+#[doc = " Return this date on the given day, keeping the year and the month (leap correct).\n\n The day is limited to the days of the month (e.g. 31 on the 11th month is 30)."]
+#[cfg(feature = "c")]
+#[unsafe(no_mangle)]
+pub extern "C" fn date_with_day(this: Date, day: UMonthDay) -> Date {
+    Date::with_day(this.into(), day.into()).into()
+}
+#[doc = " Return this date on the given day, keeping the year and the month (leap correct).\n\n The day is limited to the days of the month (e.g. 31 on the 11th month is 30)."]
+#[cfg_attr(feature = "py", pyfunction)]
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+pub fn jelal_date_with_day(this: Date, day: UMonthDay) -> Date {
+    Date::with_day(this.into(), day.into()).into()
+}
+#[doc = " Return an unsigned key which is ordered exactly as dates are (earlier is smaller).\n\n The year (offset to be unsigned) is kept in the higher bits and the ordinal in the lower\n ones, so the keys are ordered, even across year 0 and [`Self::EPOCH`], but not consecutive.\n Use [`Self::from_sort_key`] for the inverse."]
+#[cfg(feature = "c")]
+#[unsafe(no_mangle)]
+pub extern "C" fn date_to_sort_key(this: &Date) -> u64 {
+    Date::to_sort_key(&this.clone().into()).into()
+}
+#[doc = " Return an unsigned key which is ordered exactly as dates are (earlier is smaller).\n\n The year (offset to be unsigned) is kept in the higher bits and the ordinal in the lower\n ones, so the keys are ordered, even across year 0 and [`Self::EPOCH`], but not consecutive.\n Use [`Self::from_sort_key`] for the inverse."]
+#[cfg_attr(feature = "py", pyfunction)]
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+pub fn jelal_date_to_sort_key(this: &Date) -> u64 {
+    Date::to_sort_key(&this.clone().into()).into()
+}
+#[doc = " Create a date from a key made with [`Self::to_sort_key`] and saturate if invalid."]
+#[cfg(feature = "c")]
+#[unsafe(no_mangle)]
+pub extern "C" fn date_from_sort_key(key: u64) -> Date {
+    Date::from_sort_key(key.into()).into()
+}
+#[doc = " Create a date from a key made with [`Self::to_sort_key`] and saturate if invalid."]
+#[cfg_attr(feature = "py", pyfunction)]
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+pub fn jelal_date_from_sort_key(key: u64) -> Date {
+    Date::from_sort_key(key.into()).into()
+}
+#[doc = " Return the maximum length of the [`Display`] output of any date in bytes.\n\n The longest output belongs to the most negative year (with its sign) and a two digit month\n and day, separated by `/`. Use this to size fixed buffers in `no_std` environments."]
+#[cfg(feature = "c")]
+#[unsafe(no_mangle)]
+pub extern "C" fn date_max_display_len() -> usize {
+    Date::max_display_len().into()
+}
+#[doc = " Return the maximum length of the [`Display`] output of any date in bytes.\n\n The longest output belongs to the most negative year (with its sign) and a two digit month\n and day, separated by `/`. Use this to size fixed buffers in `no_std` environments."]
+#[cfg_attr(feature = "py", pyfunction)]
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+pub fn jelal_date_max_display_len() -> usize {
+    Date::max_display_len().into()
+}
+#[doc = " Return the day of the week of this date."]
+#[cfg(feature = "c")]
+#[unsafe(no_mangle)]
+pub extern "C" fn date_weekday(this: &Date) -> Weekday {
+    Date::weekday(&this.clone().into()).into()
+}
+#[doc = " Return the day of the week of this date."]
+#[cfg_attr(feature = "py", pyfunction)]
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+pub fn jelal_date_weekday(this: &Date) -> Weekday {
+    Date::weekday(&this.clone().into()).into()
+}
+#[doc = " Return the season of this date."]
+#[cfg(feature = "c")]
+#[unsafe(no_mangle)]
+pub extern "C" fn date_season(this: &Date) -> Season {
+    Date::season(&this.clone().into()).into()
+}
+#[doc = " Return the season of this date."]
+#[cfg_attr(feature = "py", pyfunction)]
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+pub fn jelal_date_season(this: &Date) -> Season {
+    Date::season(&this.clone().into()).into()
+}
+#[doc = " Is this date a weekend in the official Iranian week (only [`Weekday::Friday`]).\n\n See [`Self::is_weekend_two_day`] for the Thursday and Friday weekends."]
+#[cfg(feature = "c")]
+#[unsafe(no_mangle)]
+pub extern "C" fn date_is_weekend(this: &Date) -> bool {
+    Date::is_weekend(&this.clone().into()).into()
+}
+#[doc = " Is this date a weekend in the official Iranian week (only [`Weekday::Friday`]).\n\n See [`Self::is_weekend_two_day`] for the Thursday and Friday weekends."]
+#[cfg_attr(feature = "py", pyfunction)]
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+pub fn jelal_date_is_weekend(this: &Date) -> bool {
+    Date::is_weekend(&this.clone().into()).into()
+}
+#[doc = " Is this date a weekend if both [`Weekday::Thursday`] and [`Weekday::Friday`] are off."]
+#[cfg(feature = "c")]
+#[unsafe(no_mangle)]
+pub extern "C" fn date_is_weekend_two_day(this: &Date) -> bool {
+    Date::is_weekend_two_day(&this.clone().into()).into()
+}
+#[doc = " Is this date a weekend if both [`Weekday::Thursday`] and [`Weekday::Friday`] are off."]
+#[cfg_attr(feature = "py", pyfunction)]
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+pub fn jelal_date_is_weekend_two_day(this: &Date) -> bool {
+    Date::is_weekend_two_day(&this.clone().into()).into()
+}
+#[doc = " Return the first day of the week of this date given the day that weeks start on."]
+#[cfg(feature = "c")]
+#[unsafe(no_mangle)]
+pub extern "C" fn date_start_of_week(this: &Date, start: WeekStart) -> Date {
+    Date::start_of_week(&this.clone().into(), start.into()).into()
+}
+#[doc = " Return the first day of the week of this date given the day that weeks start on."]
+#[cfg_attr(feature = "py", pyfunction)]
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+pub fn jelal_date_start_of_week(this: &Date, start: WeekStart) -> Date {
+    Date::start_of_week(&this.clone().into(), start.into()).into()
+}
+#[doc = " Return the start of the week of this date or the next one, whichever is nearer.\n\n The days up to 3 days after `start` round back to the start of their week and the rest\n round forward to the start of the next week. Since the days are whole, a tie (3.5 days) is\n impossible and the middle day of the week (3 days after `start`) rounds back."]
+#[cfg(feature = "c")]
+#[unsafe(no_mangle)]
+pub extern "C" fn date_round_to_week(this: &Date, start: WeekStart) -> Date {
+    Date::round_to_week(&this.clone().into(), start.into()).into()
+}
+#[doc = " Return the start of the week of this date or the next one, whichever is nearer.\n\n The days up to 3 days after `start` round back to the start of their week and the rest\n round forward to the start of the next week. Since the days are whole, a tie (3.5 days) is\n impossible and the middle day of the week (3 days after `start`) rounds back."]
+#[cfg_attr(feature = "py", pyfunction)]
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+pub fn jelal_date_round_to_week(this: &Date, start: WeekStart) -> Date {
+    Date::round_to_week(&this.clone().into(), start.into()).into()
+}
+#[doc = " Return the day of the year of this date (1 to 366, same as [`Self::ordinal`])."]
+#[cfg(feature = "c")]
+#[unsafe(no_mangle)]
+pub extern "C" fn date_day_of_year(this: &Date) -> UOrdinal {
+    Date::day_of_year(&this.clone().into()).into()
+}
+#[doc = " Return the day of the year of this date (1 to 366, same as [`Self::ordinal`])."]
+#[cfg_attr(feature = "py", pyfunction)]
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+pub fn jelal_date_day_of_year(this: &Date) -> UOrdinal {
+    Date::day_of_year(&this.clone().into()).into()
+}
+#[doc = " Return the 1-based week of the year of this date with weeks starting on Saturday (Shanbe).\n\n The first week is the one containing the 1st of Farvardin, even if it is partial (e.g. when\n the year starts on a Friday, the Saturday after is already in the 2nd week). So the result\n is from 1 to 54."]
+#[cfg(feature = "c")]
+#[unsafe(no_mangle)]
+pub extern "C" fn date_week_of_year(this: &Date) -> u8 {
+    Date::week_of_year(&this.clone().into()).into()
+}
+#[doc = " Return the 1-based week of the year of this date with weeks starting on Saturday (Shanbe).\n\n The first week is the one containing the 1st of Farvardin, even if it is partial (e.g. when\n the year starts on a Friday, the Saturday after is already in the 2nd week). So the result\n is from 1 to 54."]
+#[cfg_attr(feature = "py", pyfunction)]
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+pub fn jelal_date_week_of_year(this: &Date) -> u8 {
+    Date::week_of_year(&this.clone().into()).into()
+}
+#[doc = " Return the number of days in the given month of the given year (leap correct)."]
+#[cfg(feature = "c")]
+#[unsafe(no_mangle)]
+pub extern "C" fn date_days_in_month(year: IYear, month: UMonth) -> UMonthDay {
+    Date::days_in_month(year.into(), month.into()).into()
+}
+#[doc = " Return the number of days in the given month of the given year (leap correct)."]
+#[cfg_attr(feature = "py", pyfunction)]
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+pub fn jelal_date_days_in_month(year: IYear, month: UMonth) -> UMonthDay {
+    Date::days_in_month(year.into(), month.into()).into()
+}
+#[doc = " Return the day of the week that the given month of the given year starts on."]
+#[cfg(feature = "c")]
+#[unsafe(no_mangle)]
+pub extern "C" fn date_month_start_weekday(year: IYear, month: UMonth) -> Weekday {
+    Date::month_start_weekday(year.into(), month.into()).into()
+}
+#[doc = " Return the day of the week that the given month of the given year starts on."]
+#[cfg_attr(feature = "py", pyfunction)]
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+pub fn jelal_date_month_start_weekday(year: IYear, month: UMonth) -> Weekday {
+    Date::month_start_weekday(year.into(), month.into()).into()
+}
+#[doc = " Is the year of this date a leap year (see [`Year::is_leap`])."]
+#[cfg(feature = "c")]
+#[unsafe(no_mangle)]
+pub extern "C" fn date_is_leap_year(this: &Date) -> bool {
+    Date::is_leap_year(&this.clone().into()).into()
+}
+#[doc = " Is the year of this date a leap year (see [`Year::is_leap`])."]
+#[cfg_attr(feature = "py", pyfunction)]
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+pub fn jelal_date_is_leap_year(this: &Date) -> bool {
+    Date::is_leap_year(&this.clone().into()).into()
+}
+#[doc = " Return the number of days in the year of this date (365 or 366 for leaps)."]
+#[cfg(feature = "c")]
+#[unsafe(no_mangle)]
+pub extern "C" fn date_days_in_year(this: &Date) -> UOrdinal {
+    Date::days_in_year(&this.clone().into()).into()
+}
+#[doc = " Return the number of days in the year of this date (365 or 366 for leaps)."]
+#[cfg_attr(feature = "py", pyfunction)]
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+pub fn jelal_date_days_in_year(this: &Date) -> UOrdinal {
+    Date::days_in_year(&this.clone().into()).into()
+}
+#[doc = " Return how much of the month is passed (including today) in thousandths (1 to 1000)."]
+#[cfg(feature = "c")]
+#[unsafe(no_mangle)]
+pub extern "C" fn date_month_progress_permille(this: &Date) -> u16 {
+    Date::month_progress_permille(&this.clone().into()).into()
+}
+#[doc = " Return how much of the month is passed (including today) in thousandths (1 to 1000)."]
+#[cfg_attr(feature = "py", pyfunction)]
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+pub fn jelal_date_month_progress_permille(this: &Date) -> u16 {
+    Date::month_progress_permille(&this.clone().into()).into()
+}
+#[doc = " Create a date from the proleptic Gregorian year, month (1-12) and day (1-31).\n\n The month and the day are limited to their valid values (e.g. 2025-02-30 is 2025-02-28)\n and the year is saturated to the range of [`Self::diff_epoch`]."]
+#[cfg(feature = "c")]
+#[unsafe(no_mangle)]
+pub extern "C" fn date_from_gregorian(year: i32, month: u8, day: u8) -> Date {
+    Date::from_gregorian(year.into(), month.into(), day.into()).into()
+}
+#[doc = " Create a date from the proleptic Gregorian year, month (1-12) and day (1-31).\n\n The month and the day are limited to their valid values (e.g. 2025-02-30 is 2025-02-28)\n and the year is saturated to the range of [`Self::diff_epoch`]."]
+#[cfg_attr(feature = "py", pyfunction)]
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+pub fn jelal_date_from_gregorian(year: i32, month: u8, day: u8) -> Date {
+    Date::from_gregorian(year.into(), month.into(), day.into()).into()
+}
+#[doc = " Return the day of the year (from 1) of the proleptic Gregorian date equal to this date."]
+#[cfg(feature = "c")]
+#[unsafe(no_mangle)]
+pub extern "C" fn date_gregorian_ordinal(this: &Date) -> u16 {
+    Date::gregorian_ordinal(&this.clone().into()).into()
+}
+#[doc = " Return the day of the year (from 1) of the proleptic Gregorian date equal to this date."]
+#[cfg_attr(feature = "py", pyfunction)]
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+pub fn jelal_date_gregorian_ordinal(this: &Date) -> u16 {
+    Date::gregorian_ordinal(&this.clone().into()).into()
+}
+#[doc = " Convert this [`Self::to_jtm`] but on the given struct."]
+#[cfg(feature = "c")]
+#[cfg(feature = "c")]
+#[unsafe(no_mangle)]
+pub extern "C" fn date_update_jtm(this: &Date, jtm: &mut tm) {
+    let mut __jtm = jtm.clone().into();
+    let result = Date::update_jtm(&this.clone().into(), &mut __jtm);
+    *jtm = __jtm.into();
+    result.into()
+}
+#[doc = " Convert this [`Self::to_jtm`] but on the given struct."]
+#[cfg(feature = "c")]
+#[cfg_attr(feature = "py", pyfunction)]
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+pub fn jelal_date_update_jtm(this: &Date, jtm: &mut tm) {
+    let mut __jtm = jtm.clone().into();
+    let result = Date::update_jtm(&this.clone().into(), &mut __jtm);
+    *jtm = __jtm.into();
+    result.into()
+}
+#[doc = " Create an [`ffi::tm`] from this date in Jalali.\n\n If the aim is not to create a new instance and update an already created `tm`, use\n [`Self::update_jtm`].\n\n See its documents for how this struct's values should be interpreted when the date is\n assumed to be Jalali. In short, this is exactly as in C but year doesn't have an offset and\n only year, month, ordinal, month day and week day are set.\n\n There are many ways to interpret a `tm` back, see [`Self::from_jtm_ymd`] (`year`, `mon` and\n `mday` fields) and [`Self::from_jtm_yday`] (`year` and `yday` fields).\n\n To convert this value into a `tm` (Gregorian) use [`Self::diff_epoch`] and then convert that\n to seconds to use with `localtime` and `gmtime`."]
+#[cfg(feature = "c")]
+#[cfg(feature = "c")]
+#[unsafe(no_mangle)]
+pub extern "C" fn date_to_jtm(this: &Date) -> tm {
+    Date::to_jtm(&this.clone().into()).into()
+}
+#[doc = " Create an [`ffi::tm`] from this date in Jalali.\n\n If the aim is not to create a new instance and update an already created `tm`, use\n [`Self::update_jtm`].\n\n See its documents for how this struct's values should be interpreted when the date is\n assumed to be Jalali. In short, this is exactly as in C but year doesn't have an offset and\n only year, month, ordinal, month day and week day are set.\n\n There are many ways to interpret a `tm` back, see [`Self::from_jtm_ymd`] (`year`, `mon` and\n `mday` fields) and [`Self::from_jtm_yday`] (`year` and `yday` fields).\n\n To convert this value into a `tm` (Gregorian) use [`Self::diff_epoch`] and then convert that\n to seconds to use with `localtime` and `gmtime`."]
+#[cfg(feature = "c")]
+#[cfg_attr(feature = "py", pyfunction)]
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+pub fn jelal_date_to_jtm(this: &Date) -> tm {
+    Date::to_jtm(&this.clone().into()).into()
+}
+#[doc = " Create a date from the `tm_year`, `tm_mon` and `tm_mday` of an [`ffi::tm`] in Jalali.\n\n This is the reverse of [`Self::to_jtm`] and the zero-based `tm_mon` is offset by one.\n Invalid fields saturate (e.g. 31 on the 7th month is 30) and year 0 is replaced by\n [`Year::new`]."]
+#[cfg(feature = "c")]
+#[cfg(feature = "c")]
+#[unsafe(no_mangle)]
+pub extern "C" fn date_from_jtm_ymd(jtm: &tm) -> Date {
+    Date::from_jtm_ymd(&jtm.clone().into()).into()
+}
+#[doc = " Create a date from the `tm_year`, `tm_mon` and `tm_mday` of an [`ffi::tm`] in Jalali.\n\n This is the reverse of [`Self::to_jtm`] and the zero-based `tm_mon` is offset by one.\n Invalid fields saturate (e.g. 31 on the 7th month is 30) and year 0 is replaced by\n [`Year::new`]."]
+#[cfg(feature = "c")]
+#[cfg_attr(feature = "py", pyfunction)]
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+pub fn jelal_date_from_jtm_ymd(jtm: &tm) -> Date {
+    Date::from_jtm_ymd(&jtm.clone().into()).into()
+}
+#[doc = " Create a date from the `tm_year` and `tm_yday` of an [`ffi::tm`] in Jalali.\n\n This is the reverse of [`Self::to_jtm`] and the zero-based `tm_yday` is offset by one.\n Invalid fields saturate (e.g. 365 in a non-leap year is its last day) and year 0 is\n replaced by [`Year::new`]."]
+#[cfg(feature = "c")]
+#[cfg(feature = "c")]
+#[unsafe(no_mangle)]
+pub extern "C" fn date_from_jtm_yday(jtm: &tm) -> Date {
+    Date::from_jtm_yday(&jtm.clone().into()).into()
+}
+#[doc = " Create a date from the `tm_year` and `tm_yday` of an [`ffi::tm`] in Jalali.\n\n This is the reverse of [`Self::to_jtm`] and the zero-based `tm_yday` is offset by one.\n Invalid fields saturate (e.g. 365 in a non-leap year is its last day) and year 0 is\n replaced by [`Year::new`]."]
+#[cfg(feature = "c")]
+#[cfg_attr(feature = "py", pyfunction)]
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+pub fn jelal_date_from_jtm_yday(jtm: &tm) -> Date {
+    Date::from_jtm_yday(&jtm.clone().into()).into()
+}
+#[doc = " Const-context definition of [`Ord::max`] (returns `other` if both are equal)."]
+#[cfg(feature = "c")]
+#[unsafe(no_mangle)]
+pub extern "C" fn date_max(this: Date, other: Date) -> Date {
+    Date::max(this.into(), other.into()).into()
+}
+#[doc = " Const-context definition of [`Ord::max`] (returns `other` if both are equal)."]
+#[cfg_attr(feature = "py", pyfunction)]
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+pub fn jelal_date_max(this: Date, other: Date) -> Date {
+    Date::max(this.into(), other.into()).into()
+}
+#[doc = " Const-context definition of [`Ord::min`] (returns `self` if both are equal)."]
+#[cfg(feature = "c")]
+#[unsafe(no_mangle)]
+pub extern "C" fn date_min(this: Date, other: Date) -> Date {
+    Date::min(this.into(), other.into()).into()
+}
+#[doc = " Const-context definition of [`Ord::min`] (returns `self` if both are equal)."]
+#[cfg_attr(feature = "py", pyfunction)]
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+pub fn jelal_date_min(this: Date, other: Date) -> Date {
+    Date::min(this.into(), other.into()).into()
+}
+#[doc = " Const-context definition of [`Ord::clamp`] limiting this date to `min` and `max`.\n\n Unlike [`Ord::clamp`], this does not panic if `min` is greater than `max` and returns `min`."]
+#[cfg(feature = "c")]
+#[unsafe(no_mangle)]
+pub extern "C" fn date_clamp(this: Date, min: Date, max: Date) -> Date {
+    Date::clamp(this.into(), min.into(), max.into()).into()
+}
+#[doc = " Const-context definition of [`Ord::clamp`] limiting this date to `min` and `max`.\n\n Unlike [`Ord::clamp`], this does not panic if `min` is greater than `max` and returns `min`."]
+#[cfg_attr(feature = "py", pyfunction)]
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+pub fn jelal_date_clamp(this: Date, min: Date, max: Date) -> Date {
+    Date::clamp(this.into(), min.into(), max.into()).into()
+}
+#[doc = " Const-context definition of [`Ord::cmp`]."]
+#[cfg(feature = "c")]
+#[unsafe(no_mangle)]
+pub extern "C" fn date_cmp(this: &Date, other: &Date) -> Ordering {
+    Date::cmp(&this.clone().into(), &other.clone().into()).into()
+}
+#[doc = " Const-context definition of [`Ord::cmp`]."]
+#[cfg_attr(feature = "py", pyfunction)]
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+pub fn jelal_date_cmp(this: &Date, other: &Date) -> Ordering {
+    Date::cmp(&this.clone().into(), &other.clone().into()).into()
+}
+#[cfg_attr(feature = "py", pymethods)]
+impl Date {
+    #[doc = " The furthest in the past that can be represented with this struct."]
+    pub const MIN: Self = Date {
+        year: Year::MIN,
+        ordinal: Ordinal::MIN,
+    };
+    #[doc = " The furthest in the future that can be represented with this struct."]
+    pub const MAX: Self = Date {
+        year: Year::MAX,
+        ordinal: Ordinal::MAX_NON_LEAP,
+    };
+    #[doc = " Unix Epoch in this format (equivalent to Gregorian 1st of January [`MonthDay`], 1970)."]
+    pub const EPOCH: Self = Date {
+        year: Year::EPOCH,
+        ordinal: Ordinal::EPOCH,
+    };
+    #[doc = " The furthest in the past that its leap years are calculated astronomically."]
+    pub const RELIABLE_MIN: Self = Date {
+        year: Year::ASTRONOMICAL_MIN,
+        ordinal: Ordinal::MIN,
+    };
+    #[doc = " The furthest in the future that its leap years are calculated astronomically.\n\n [`Year::ASTRONOMICAL_MAX`] is not a leap year."]
+    pub const RELIABLE_MAX: Self = Date {
+        year: Year::ASTRONOMICAL_MAX,
+        ordinal: Ordinal::MAX_NON_LEAP,
+    };
+}
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+impl Date {
+    #[doc = " Add a year to this date and saturate the results at limits.\n\n This is exactly as [`Self::add_year_strict`] but returns the value only."]
+    pub fn add_year(self, year: IYear) -> Date {
+        let this = self;
+        let this: crate::Date = this.into();
+        unsafe { ::core::mem::transmute(crate::Date::add_year(this, year.into())) }
+    }
+    #[doc = " Add a ordinal to this date and saturate the results at limits.\n\n This is exactly as [`Self::add_ordinal_strict`] but returns the value only."]
+    pub fn add_ordinal(self, ordinal: IOrdinal) -> Date {
+        let this = self;
+        let this: crate::Date = this.into();
+        unsafe { ::core::mem::transmute(crate::Date::add_ordinal(this, ordinal.into())) }
+    }
+    #[doc = " Add a month count to this date and saturate the results at limits.\n\n This is exactly as [`Self::add_month_strict`] but returns the value only."]
+    pub fn add_month(self, month: IMonth) -> Date {
+        let this = self;
+        let this: crate::Date = this.into();
+        unsafe { ::core::mem::transmute(crate::Date::add_month(this, month.into())) }
+    }
+    #[doc = " Add this many consecutive months to this date.\n\n This is exactly as [`Self::add_months_strict`] but returns the value only."]
+    pub fn add_months(self, months: IDayDiff) -> Date {
+        let this = self;
+        let this: crate::Date = this.into();
         unsafe { ::core::mem::transmute(crate::Date::add_months(this, months.into())) }
     }
-    #[doc = " Add or remove the given number of consecutive days to this date.\n\n This is exactly as [`Self::add_days_strict`] but returns the value only."]
-    pub fn add_days(self, days: IDayDiff) -> Date {
+    #[doc = " Add or remove the given number of consecutive days to this date.\n\n This is exactly as [`Self::add_days_strict`] but returns the value only."]
+    pub fn add_days(self, days: IDayDiff) -> Date {
+        let this = self;
+        let this: crate::Date = this.into();
+        unsafe { ::core::mem::transmute(crate::Date::add_days(this, days.into())) }
+    }
+    #[doc = " Add or remove the given number of weeks (7 days each) to this date.\n\n This is exactly as [`Self::add_weeks_strict`] but returns the value only."]
+    pub fn add_weeks(self, weeks: IDayDiff) -> Date {
+        let this = self;
+        let this: crate::Date = this.into();
+        unsafe { ::core::mem::transmute(crate::Date::add_weeks(this, weeks.into())) }
+    }
+    #[doc = " Return the date of \"today\" given how many days are passed since Unix Epoch ([`Self::EPOCH`]).\n\n This is a pure alternative of reading the clock, so the code depending on the date of today\n can be tested deterministically by injecting the day count instead of mocking the clock."]
+    pub fn today_from_epoch_day(epoch_day: IDayDiff) -> Date {
+        unsafe { ::core::mem::transmute(crate::Date::today_from_epoch_day(epoch_day.into())) }
+    }
+    #[doc = " Return the date (in UTC) of the given Unix timestamp (seconds after Unix Epoch).\n\n Negative seconds are before the epoch and any part of a day is counted as that whole day\n (e.g. -1 is 1969-12-31). See [`Self::from_epoch_days`]."]
+    pub fn from_unix_timestamp(secs: i64) -> Date {
+        unsafe { ::core::mem::transmute(crate::Date::from_unix_timestamp(secs.into())) }
+    }
+    #[doc = " Return this date in the given year, keeping the month and the day (leap correct).\n\n The last day of a leap year is limited to the last day of the given year if not leap."]
+    pub fn with_year(self, year: IYear) -> Date {
+        let this = self;
+        let this: crate::Date = this.into();
+        unsafe { ::core::mem::transmute(crate::Date::with_year(this, year.into())) }
+    }
+    #[doc = " Return this date in the given month, keeping the year and the day (leap correct).\n\n The day is limited to the last day of the given month (e.g. 31 on the 11th month is 30)."]
+    pub fn with_month(self, month: UMonth) -> Date {
+        let this = self;
+        let this: crate::Date = this.into();
+        unsafe { ::core::mem::transmute(crate::Date::with_month(this, month.into())) }
+    }
+    #[doc = " Return this date on the given day, keeping the year and the month (leap correct).\n\n The day is limited to the days of the month (e.g. 31 on the 11th month is 30)."]
+    pub fn with_day(self, day: UMonthDay) -> Date {
+        let this = self;
+        let this: crate::Date = this.into();
+        unsafe { ::core::mem::transmute(crate::Date::with_day(this, day.into())) }
+    }
+    #[doc = " Create a date from a key made with [`Self::to_sort_key`] and saturate if invalid."]
+    pub fn from_sort_key(key: u64) -> Date {
+        unsafe { ::core::mem::transmute(crate::Date::from_sort_key(key.into())) }
+    }
+    #[doc = " Return the maximum length of the [`Display`] output of any date in bytes.\n\n The longest output belongs to the most negative year (with its sign) and a two digit month\n and day, separated by `/`. Use this to size fixed buffers in `no_std` environments."]
+    pub fn max_display_len() -> usize {
+        unsafe { ::core::mem::transmute(crate::Date::max_display_len()) }
+    }
+    #[doc = " Return the number of days in the given month of the given year (leap correct)."]
+    pub fn days_in_month(year: IYear, month: UMonth) -> UMonthDay {
+        unsafe { ::core::mem::transmute(crate::Date::days_in_month(year.into(), month.into())) }
+    }
+    #[doc = " Return the day of the week that the given month of the given year starts on."]
+    pub fn month_start_weekday(year: IYear, month: UMonth) -> Weekday {
+        unsafe {
+            ::core::mem::transmute(crate::Date::month_start_weekday(year.into(), month.into()))
+        }
+    }
+    #[doc = " Create a date from the proleptic Gregorian year, month (1-12) and day (1-31).\n\n The month and the day are limited to their valid values (e.g. 2025-02-30 is 2025-02-28)\n and the year is saturated to the range of [`Self::diff_epoch`]."]
+    pub fn from_gregorian(year: i32, month: u8, day: u8) -> Date {
+        unsafe {
+            ::core::mem::transmute(crate::Date::from_gregorian(
+                year.into(),
+                month.into(),
+                day.into(),
+            ))
+        }
+    }
+    #[doc = " Create a date from the `tm_year`, `tm_mon` and `tm_mday` of an [`ffi::tm`] in Jalali.\n\n This is the reverse of [`Self::to_jtm`] and the zero-based `tm_mon` is offset by one.\n Invalid fields saturate (e.g. 31 on the 7th month is 30) and year 0 is replaced by\n [`Year::new`]."]
+    #[cfg(feature = "c")]
+    pub fn from_jtm_ymd(jtm: &tm) -> Date {
+        unsafe { ::core::mem::transmute(crate::Date::from_jtm_ymd(&jtm.clone().into())) }
+    }
+    #[doc = " Create a date from the `tm_year` and `tm_yday` of an [`ffi::tm`] in Jalali.\n\n This is the reverse of [`Self::to_jtm`] and the zero-based `tm_yday` is offset by one.\n Invalid fields saturate (e.g. 365 in a non-leap year is its last day) and year 0 is\n replaced by [`Year::new`]."]
+    #[cfg(feature = "c")]
+    pub fn from_jtm_yday(jtm: &tm) -> Date {
+        unsafe { ::core::mem::transmute(crate::Date::from_jtm_yday(&jtm.clone().into())) }
+    }
+    #[doc = " Const-context definition of [`Ord::max`] (returns `other` if both are equal)."]
+    pub fn max(self, other: Date) -> Date {
         let this = self;
         let this: crate::Date = this.into();
-        unsafe { ::core::mem::transmute(crate::Date::add_days(this, days.into())) }
+        unsafe { ::core::mem::transmute(crate::Date::max(this, other.into())) }
+    }
+    #[doc = " Const-context definition of [`Ord::min`] (returns `self` if both are equal)."]
+    pub fn min(self, other: Date) -> Date {
+        let this = self;
+        let this: crate::Date = this.into();
+        unsafe { ::core::mem::transmute(crate::Date::min(this, other.into())) }
+    }
+    #[doc = " Const-context definition of [`Ord::clamp`] limiting this date to `min` and `max`.\n\n Unlike [`Ord::clamp`], this does not panic if `min` is greater than `max` and returns `min`."]
+    pub fn clamp(self, min: Date, max: Date) -> Date {
+        let this = self;
+        let this: crate::Date = this.into();
+        unsafe { ::core::mem::transmute(crate::Date::clamp(this, min.into(), max.into())) }
     }
     #[cfg_attr(feature = "wasm", wasm_bindgen(constructor))]
     #[doc = " Create a new Jalali date or slightly change values to be valid."]
@@ -939,6 +1989,142 @@ impl Date {
         let this: crate::Date = this.into();
         unsafe { ::core::mem::transmute(crate::Date::add_days(this, days.into())) }
     }
+    #[doc = " Add or remove the given number of weeks (7 days each) to this date.\n\n This is exactly as [`Self::add_weeks_strict`] but returns the value only."]
+    #[cfg(feature = "py")]
+    #[pyo3(name = "add_weeks")]
+    fn __py_only_add_weeks(&self, weeks: IDayDiff) -> Date {
+        let this = self;
+        let this: Self = this.clone();
+        let this: crate::Date = this.into();
+        unsafe { ::core::mem::transmute(crate::Date::add_weeks(this, weeks.into())) }
+    }
+    #[doc = " Return the date of \"today\" given how many days are passed since Unix Epoch ([`Self::EPOCH`]).\n\n This is a pure alternative of reading the clock, so the code depending on the date of today\n can be tested deterministically by injecting the day count instead of mocking the clock."]
+    #[cfg(feature = "py")]
+    #[pyo3(name = "today_from_epoch_day")]
+    #[staticmethod]
+    fn __py_only_today_from_epoch_day(epoch_day: IDayDiff) -> Date {
+        unsafe { ::core::mem::transmute(crate::Date::today_from_epoch_day(epoch_day.into())) }
+    }
+    #[doc = " Return the date (in UTC) of the given Unix timestamp (seconds after Unix Epoch).\n\n Negative seconds are before the epoch and any part of a day is counted as that whole day\n (e.g. -1 is 1969-12-31). See [`Self::from_epoch_days`]."]
+    #[cfg(feature = "py")]
+    #[pyo3(name = "from_unix_timestamp")]
+    #[staticmethod]
+    fn __py_only_from_unix_timestamp(secs: i64) -> Date {
+        unsafe { ::core::mem::transmute(crate::Date::from_unix_timestamp(secs.into())) }
+    }
+    #[doc = " Return this date in the given year, keeping the month and the day (leap correct).\n\n The last day of a leap year is limited to the last day of the given year if not leap."]
+    #[cfg(feature = "py")]
+    #[pyo3(name = "with_year")]
+    fn __py_only_with_year(&self, year: IYear) -> Date {
+        let this = self;
+        let this: Self = this.clone();
+        let this: crate::Date = this.into();
+        unsafe { ::core::mem::transmute(crate::Date::with_year(this, year.into())) }
+    }
+    #[doc = " Return this date in the given month, keeping the year and the day (leap correct).\n\n The day is limited to the last day of the given month (e.g. 31 on the 11th month is 30)."]
+    #[cfg(feature = "py")]
+    #[pyo3(name = "with_month")]
+    fn __py_only_with_month(&self, month: UMonth) -> Date {
+        let this = self;
+        let this: Self = this.clone();
+        let this: crate::Date = this.into();
+        unsafe { ::core::mem::transmute(crate::Date::with_month(this, month.into())) }
+    }
+    #[doc = " Return this date on the given day, keeping the year and the month (leap correct).\n\n The day is limited to the days of the month (e.g. 31 on the 11th month is 30)."]
+    #[cfg(feature = "py")]
+    #[pyo3(name = "with_day")]
+    fn __py_only_with_day(&self, day: UMonthDay) -> Date {
+        let this = self;
+        let this: Self = this.clone();
+        let this: crate::Date = this.into();
+        unsafe { ::core::mem::transmute(crate::Date::with_day(this, day.into())) }
+    }
+    #[doc = " Create a date from a key made with [`Self::to_sort_key`] and saturate if invalid."]
+    #[cfg(feature = "py")]
+    #[pyo3(name = "from_sort_key")]
+    #[staticmethod]
+    fn __py_only_from_sort_key(key: u64) -> Date {
+        unsafe { ::core::mem::transmute(crate::Date::from_sort_key(key.into())) }
+    }
+    #[doc = " Return the maximum length of the [`Display`] output of any date in bytes.\n\n The longest output belongs to the most negative year (with its sign) and a two digit month\n and day, separated by `/`. Use this to size fixed buffers in `no_std` environments."]
+    #[cfg(feature = "py")]
+    #[pyo3(name = "max_display_len")]
+    #[staticmethod]
+    fn __py_only_max_display_len() -> usize {
+        unsafe { ::core::mem::transmute(crate::Date::max_display_len()) }
+    }
+    #[doc = " Return the number of days in the given month of the given year (leap correct)."]
+    #[cfg(feature = "py")]
+    #[pyo3(name = "days_in_month")]
+    #[staticmethod]
+    fn __py_only_days_in_month(year: IYear, month: UMonth) -> UMonthDay {
+        unsafe { ::core::mem::transmute(crate::Date::days_in_month(year.into(), month.into())) }
+    }
+    #[doc = " Return the day of the week that the given month of the given year starts on."]
+    #[cfg(feature = "py")]
+    #[pyo3(name = "month_start_weekday")]
+    #[staticmethod]
+    fn __py_only_month_start_weekday(year: IYear, month: UMonth) -> Weekday {
+        unsafe {
+            ::core::mem::transmute(crate::Date::month_start_weekday(year.into(), month.into()))
+        }
+    }
+    #[doc = " Create a date from the proleptic Gregorian year, month (1-12) and day (1-31).\n\n The month and the day are limited to their valid values (e.g. 2025-02-30 is 2025-02-28)\n and the year is saturated to the range of [`Self::diff_epoch`]."]
+    #[cfg(feature = "py")]
+    #[pyo3(name = "from_gregorian")]
+    #[staticmethod]
+    fn __py_only_from_gregorian(year: i32, month: u8, day: u8) -> Date {
+        unsafe {
+            ::core::mem::transmute(crate::Date::from_gregorian(
+                year.into(),
+                month.into(),
+                day.into(),
+            ))
+        }
+    }
+    #[doc = " Create a date from the `tm_year`, `tm_mon` and `tm_mday` of an [`ffi::tm`] in Jalali.\n\n This is the reverse of [`Self::to_jtm`] and the zero-based `tm_mon` is offset by one.\n Invalid fields saturate (e.g. 31 on the 7th month is 30) and year 0 is replaced by\n [`Year::new`]."]
+    #[cfg(feature = "c")]
+    #[cfg(feature = "py")]
+    #[pyo3(name = "from_jtm_ymd")]
+    #[staticmethod]
+    fn __py_only_from_jtm_ymd(jtm: &tm) -> Date {
+        unsafe { ::core::mem::transmute(crate::Date::from_jtm_ymd(&jtm.clone().into())) }
+    }
+    #[doc = " Create a date from the `tm_year` and `tm_yday` of an [`ffi::tm`] in Jalali.\n\n This is the reverse of [`Self::to_jtm`] and the zero-based `tm_yday` is offset by one.\n Invalid fields saturate (e.g. 365 in a non-leap year is its last day) and year 0 is\n replaced by [`Year::new`]."]
+    #[cfg(feature = "c")]
+    #[cfg(feature = "py")]
+    #[pyo3(name = "from_jtm_yday")]
+    #[staticmethod]
+    fn __py_only_from_jtm_yday(jtm: &tm) -> Date {
+        unsafe { ::core::mem::transmute(crate::Date::from_jtm_yday(&jtm.clone().into())) }
+    }
+    #[doc = " Const-context definition of [`Ord::max`] (returns `other` if both are equal)."]
+    #[cfg(feature = "py")]
+    #[pyo3(name = "max")]
+    fn __py_only_max(&self, other: Date) -> Date {
+        let this = self;
+        let this: Self = this.clone();
+        let this: crate::Date = this.into();
+        unsafe { ::core::mem::transmute(crate::Date::max(this, other.into())) }
+    }
+    #[doc = " Const-context definition of [`Ord::min`] (returns `self` if both are equal)."]
+    #[cfg(feature = "py")]
+    #[pyo3(name = "min")]
+    fn __py_only_min(&self, other: Date) -> Date {
+        let this = self;
+        let this: Self = this.clone();
+        let this: crate::Date = this.into();
+        unsafe { ::core::mem::transmute(crate::Date::min(this, other.into())) }
+    }
+    #[doc = " Const-context definition of [`Ord::clamp`] limiting this date to `min` and `max`.\n\n Unlike [`Ord::clamp`], this does not panic if `min` is greater than `max` and returns `min`."]
+    #[cfg(feature = "py")]
+    #[pyo3(name = "clamp")]
+    fn __py_only_clamp(&self, min: Date, max: Date) -> Date {
+        let this = self;
+        let this: Self = this.clone();
+        let this: crate::Date = this.into();
+        unsafe { ::core::mem::transmute(crate::Date::clamp(this, min.into(), max.into())) }
+    }
     #[cfg(feature = "py")]
     #[new]
     #[doc = " Create a new Jalali date or slightly change values to be valid."]
@@ -953,7 +2139,7 @@ pub extern "C" fn date_ext_cmp(this: &Date, other: &Date) -> i8 {
 }
 #[cfg_attr(feature = "py", pyfunction)]
 #[cfg_attr(feature = "wasm", wasm_bindgen)]
-pub fn _date_ext_cmp(this: &Date, other: &Date) -> i8 {
+pub fn jelal_date_ext_cmp(this: &Date, other: &Date) -> i8 {
     Date::ext_cmp(&this.clone().into(), &other.clone().into()).into()
 }
 #[cfg_attr(feature = "wasm", wasm_bindgen)]
@@ -963,6 +2149,25 @@ impl Date {
         crate::Date::from(self.clone()).cmp(&crate::Date::from(other.clone())) as i8
     }
 }
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+impl Date {
+    #[doc = " FFI version of an `Ord` comparison operator"]
+    pub fn ext_lt(&self, other: &Self) -> bool {
+        self.ext_cmp(other) < 0
+    }
+    #[doc = " FFI version of an `Ord` comparison operator"]
+    pub fn ext_le(&self, other: &Self) -> bool {
+        self.ext_cmp(other) <= 0
+    }
+    #[doc = " FFI version of an `Ord` comparison operator"]
+    pub fn ext_gt(&self, other: &Self) -> bool {
+        self.ext_cmp(other) > 0
+    }
+    #[doc = " FFI version of an `Ord` comparison operator"]
+    pub fn ext_ge(&self, other: &Self) -> bool {
+        self.ext_cmp(other) >= 0
+    }
+}
 #[doc = " FFI version of a `From` trait implementation"]
 #[cfg(feature = "c")]
 #[unsafe(no_mangle)]
@@ -972,7 +2177,7 @@ pub extern "C" fn date_ext_from_year(value: IYear) -> Date {
 #[doc = " FFI version of a `From` trait implementation"]
 #[cfg_attr(feature = "py", pyfunction)]
 #[cfg_attr(feature = "wasm", wasm_bindgen)]
-pub fn _date_ext_from_year(value: IYear) -> Date {
+pub fn jelal_date_ext_from_year(value: IYear) -> Date {
     Date::ext_from_year(value.into()).into()
 }
 #[cfg(feature = "py")]
@@ -1002,7 +2207,7 @@ pub extern "C" fn date_ext_from_iyear(value: IYear) -> Date {
 #[doc = " FFI version of a `From` trait implementation"]
 #[cfg_attr(feature = "py", pyfunction)]
 #[cfg_attr(feature = "wasm", wasm_bindgen)]
-pub fn _date_ext_from_iyear(value: IYear) -> Date {
+pub fn jelal_date_ext_from_iyear(value: IYear) -> Date {
     Date::ext_from_iyear(value.into()).into()
 }
 #[cfg(feature = "py")]
@@ -1023,6 +2228,70 @@ impl Date {
         crate::Date::from(crate::IYear::from(value)).into()
     }
 }
+#[doc = " FFI version of a `Display` trait implementation\n\n Write the text and a null \
+                              byte to `buf` (`len` bytes at most) and return the length of the text \
+                              (without null) even if it did not fit (`snprintf` like).\n\n # Safety\n \
+                              `buf` must be null or valid for writing `len` bytes."]
+#[cfg(feature = "c")]
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn monthday_to_string(
+    this: &MonthDay,
+    buf: *mut c_char,
+    len: usize,
+) -> usize {
+    unsafe { write_display(&crate::MonthDay::from(this.clone()), buf, len) }
+}
+#[cfg(feature = "py")]
+#[pymethods]
+impl MonthDay {
+    #[pyo3(name = "__str__")]
+    fn __py_only_ext_to_string(&self) -> ::std::string::String {
+        ::std::string::ToString::to_string(&crate::MonthDay::from(self.clone()))
+    }
+    #[pyo3(name = "__repr__")]
+    fn __py_only_ext_repr(&self) -> ::std::string::String {
+        ::std::format!("MonthDay({})", crate::MonthDay::from(self.clone()))
+    }
+}
+#[cfg(feature = "std")]
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+impl MonthDay {
+    #[doc = " FFI version of a `Display` trait implementation"]
+    # [cfg_attr (feature = "wasm" , wasm_bindgen (js_name = toString))]
+    pub fn ext_to_string(&self) -> ::std::string::String {
+        ::std::string::ToString::to_string(&crate::MonthDay::from(self.clone()))
+    }
+}
+#[doc = " FFI version of a `Display` trait implementation\n\n Write the text and a null \
+                              byte to `buf` (`len` bytes at most) and return the length of the text \
+                              (without null) even if it did not fit (`snprintf` like).\n\n # Safety\n \
+                              `buf` must be null or valid for writing `len` bytes."]
+#[cfg(feature = "c")]
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn date_to_string(this: &Date, buf: *mut c_char, len: usize) -> usize {
+    unsafe { write_display(&crate::Date::from(this.clone()), buf, len) }
+}
+#[cfg(feature = "py")]
+#[pymethods]
+impl Date {
+    #[pyo3(name = "__str__")]
+    fn __py_only_ext_to_string(&self) -> ::std::string::String {
+        ::std::string::ToString::to_string(&crate::Date::from(self.clone()))
+    }
+    #[pyo3(name = "__repr__")]
+    fn __py_only_ext_repr(&self) -> ::std::string::String {
+        ::std::format!("Date({})", crate::Date::from(self.clone()))
+    }
+}
+#[cfg(feature = "std")]
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+impl Date {
+    #[doc = " FFI version of a `Display` trait implementation"]
+    # [cfg_attr (feature = "wasm" , wasm_bindgen (js_name = toString))]
+    pub fn ext_to_string(&self) -> ::std::string::String {
+        ::std::string::ToString::to_string(&crate::Date::from(self.clone()))
+    }
+}
 impl From<Month> for crate::Month {
     fn from(value: Month) -> Self {
         Self { 0: value.0.into() }
@@ -1063,6 +2332,11 @@ pub const MONTH_MAX: UMonth = unsafe { ::core::mem::transmute(crate::Month::MAX)
 #[doc = " The last month of the Jalali year; 12: Esfand."]
 #[unsafe(export_name = "MONTH_MAX")]
 pub static _MONTH_MAX: UMonth = MONTH_MAX;
+#[doc = " All the months of the Jalali year in order (Farvardin to Esfand)."]
+pub const MONTH_MONTHS: [UMonth; 12] = unsafe { ::core::mem::transmute(crate::Month::MONTHS) };
+#[doc = " All the months of the Jalali year in order (Farvardin to Esfand)."]
+#[unsafe(export_name = "MONTH_MONTHS")]
+pub static _MONTH_MONTHS: [UMonth; 12] = MONTH_MONTHS;
 #[doc = " Convert a valid month to ordinal assuming 0th day of the month (-1) if month is valid."]
 #[cfg(feature = "c")]
 #[unsafe(no_mangle)]
@@ -1073,7 +2347,7 @@ pub extern "C" fn month_to_ordinal_assume_zero(this: UMonth) -> UOrdinal {
 #[doc = " Convert a valid month to ordinal assuming 0th day of the month (-1) if month is valid."]
 #[cfg_attr(feature = "py", pyfunction)]
 #[cfg_attr(feature = "wasm", wasm_bindgen)]
-pub fn _month_to_ordinal_assume_zero(this: UMonth) -> UOrdinal {
+pub fn jelal_month_to_ordinal_assume_zero(this: UMonth) -> UOrdinal {
     let this: Month = this.into();
     Month::to_ordinal_assume_zero(&this).into()
 }
@@ -1086,9 +2360,37 @@ pub extern "C" fn month_new(value: UMonth) -> UMonth {
 #[doc = " Create a new instance and limit it to [`Self::MIN`] and [`Self::MAX`]."]
 #[cfg_attr(feature = "py", pyfunction)]
 #[cfg_attr(feature = "wasm", wasm_bindgen)]
-pub fn _month_new(value: UMonth) -> UMonth {
+pub fn jelal_month_new(value: UMonth) -> UMonth {
     Month::new(value.into()).into()
 }
+#[doc = " Return the number of days in this month of the given year (leap correct)."]
+#[cfg(feature = "c")]
+#[unsafe(no_mangle)]
+pub extern "C" fn month_days_in(this: UMonth, year: IYear) -> UMonthDay {
+    let this: Month = this.into();
+    Month::days_in(&this, year.into()).into()
+}
+#[doc = " Return the number of days in this month of the given year (leap correct)."]
+#[cfg_attr(feature = "py", pyfunction)]
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+pub fn jelal_month_days_in(this: UMonth, year: IYear) -> UMonthDay {
+    let this: Month = this.into();
+    Month::days_in(&this, year.into()).into()
+}
+#[doc = " Return the season which this month is in."]
+#[cfg(feature = "c")]
+#[unsafe(no_mangle)]
+pub extern "C" fn month_season(this: UMonth) -> Season {
+    let this: Month = this.into();
+    Month::season(&this).into()
+}
+#[doc = " Return the season which this month is in."]
+#[cfg_attr(feature = "py", pyfunction)]
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+pub fn jelal_month_season(this: UMonth) -> Season {
+    let this: Month = this.into();
+    Month::season(&this).into()
+}
 #[doc = " Return the owned types of this value."]
 #[cfg(feature = "c")]
 #[unsafe(no_mangle)]
@@ -1099,7 +2401,7 @@ pub extern "C" fn month_get(this: UMonth) -> UMonth {
 #[doc = " Return the owned types of this value."]
 #[cfg_attr(feature = "py", pyfunction)]
 #[cfg_attr(feature = "wasm", wasm_bindgen)]
-pub fn _month_get(this: UMonth) -> UMonth {
+pub fn jelal_month_get(this: UMonth) -> UMonth {
     let this: Month = this.into();
     Month::get(&this).into()
 }
@@ -1113,7 +2415,7 @@ pub extern "C" fn month_cmp(this: UMonth, other: UMonth) -> Ordering {
 #[doc = " Const-context definition of [`Ord::cmp`]."]
 #[cfg_attr(feature = "py", pyfunction)]
 #[cfg_attr(feature = "wasm", wasm_bindgen)]
-pub fn _month_cmp(this: UMonth, other: UMonth) -> Ordering {
+pub fn jelal_month_cmp(this: UMonth, other: UMonth) -> Ordering {
     let this: Month = this.into();
     Month::cmp(&this, other.into()).into()
 }
@@ -1127,6 +2429,8 @@ impl Month {
     pub const MID: Self = unsafe { ::core::mem::transmute(crate::Month::MID) };
     #[doc = " The last month of the Jalali year; 12: Esfand."]
     pub const MAX: Self = unsafe { ::core::mem::transmute(crate::Month::MAX) };
+    #[doc = " All the months of the Jalali year in order (Farvardin to Esfand)."]
+    pub const MONTHS: [Self; 12] = unsafe { ::core::mem::transmute(crate::Month::MONTHS) };
 }
 #[cfg_attr(feature = "wasm", wasm_bindgen)]
 impl Month {
@@ -1201,9 +2505,65 @@ pub extern "C" fn ordinal_new(value: UOrdinal) -> UOrdinal {
 #[doc = " Create a new instance and limit it to [`Self::MIN`] and [`Self::MAX`]."]
 #[cfg_attr(feature = "py", pyfunction)]
 #[cfg_attr(feature = "wasm", wasm_bindgen)]
-pub fn _ordinal_new(value: UOrdinal) -> UOrdinal {
+pub fn jelal_ordinal_new(value: UOrdinal) -> UOrdinal {
     Ordinal::new(value.into()).into()
 }
+#[doc = " Convert this day of the year to its month and day (leap correct).\n\n This is the reverse of [`MonthDay::to_ordinal`], for example `Ordinal::new(44)` is the 13th\n of the second month (`2/13`) and [`Self::MAX`] is the 30th of the last."]
+#[cfg(feature = "c")]
+#[unsafe(no_mangle)]
+pub extern "C" fn ordinal_to_month_day(this: UOrdinal) -> MonthDay {
+    let this: Ordinal = this.into();
+    Ordinal::to_month_day(&this).into()
+}
+#[doc = " Convert this day of the year to its month and day (leap correct).\n\n This is the reverse of [`MonthDay::to_ordinal`], for example `Ordinal::new(44)` is the 13th\n of the second month (`2/13`) and [`Self::MAX`] is the 30th of the last."]
+#[cfg_attr(feature = "py", pyfunction)]
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+pub fn jelal_ordinal_to_month_day(this: UOrdinal) -> MonthDay {
+    let this: Ordinal = this.into();
+    Ordinal::to_month_day(&this).into()
+}
+#[doc = " Is this day in the first half of the year (before [`Self::MID`]).\n\n The first half ends on day 186 which is the 31st of the sixth month."]
+#[cfg(feature = "c")]
+#[unsafe(no_mangle)]
+pub extern "C" fn ordinal_is_first_half(this: UOrdinal) -> bool {
+    let this: Ordinal = this.into();
+    Ordinal::is_first_half(&this).into()
+}
+#[doc = " Is this day in the first half of the year (before [`Self::MID`]).\n\n The first half ends on day 186 which is the 31st of the sixth month."]
+#[cfg_attr(feature = "py", pyfunction)]
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+pub fn jelal_ordinal_is_first_half(this: UOrdinal) -> bool {
+    let this: Ordinal = this.into();
+    Ordinal::is_first_half(&this).into()
+}
+#[doc = " Is this day in the second half of the year (on or after [`Self::MID`])."]
+#[cfg(feature = "c")]
+#[unsafe(no_mangle)]
+pub extern "C" fn ordinal_is_second_half(this: UOrdinal) -> bool {
+    let this: Ordinal = this.into();
+    Ordinal::is_second_half(&this).into()
+}
+#[doc = " Is this day in the second half of the year (on or after [`Self::MID`])."]
+#[cfg_attr(feature = "py", pyfunction)]
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+pub fn jelal_ordinal_is_second_half(this: UOrdinal) -> bool {
+    let this: Ordinal = this.into();
+    Ordinal::is_second_half(&this).into()
+}
+#[doc = " Return the half of the year this day is in, 1 or 2 (see [`Self::is_first_half`])."]
+#[cfg(feature = "c")]
+#[unsafe(no_mangle)]
+pub extern "C" fn ordinal_half(this: UOrdinal) -> u8 {
+    let this: Ordinal = this.into();
+    Ordinal::half(&this).into()
+}
+#[doc = " Return the half of the year this day is in, 1 or 2 (see [`Self::is_first_half`])."]
+#[cfg_attr(feature = "py", pyfunction)]
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+pub fn jelal_ordinal_half(this: UOrdinal) -> u8 {
+    let this: Ordinal = this.into();
+    Ordinal::half(&this).into()
+}
 #[doc = " Return the owned types of this value."]
 #[cfg(feature = "c")]
 #[unsafe(no_mangle)]
@@ -1214,7 +2574,7 @@ pub extern "C" fn ordinal_get(this: UOrdinal) -> UOrdinal {
 #[doc = " Return the owned types of this value."]
 #[cfg_attr(feature = "py", pyfunction)]
 #[cfg_attr(feature = "wasm", wasm_bindgen)]
-pub fn _ordinal_get(this: UOrdinal) -> UOrdinal {
+pub fn jelal_ordinal_get(this: UOrdinal) -> UOrdinal {
     let this: Ordinal = this.into();
     Ordinal::get(&this).into()
 }
@@ -1228,7 +2588,7 @@ pub extern "C" fn ordinal_cmp(this: UOrdinal, other: UOrdinal) -> Ordering {
 #[doc = " Const-context definition of [`Ord::cmp`]."]
 #[cfg_attr(feature = "py", pyfunction)]
 #[cfg_attr(feature = "wasm", wasm_bindgen)]
-pub fn _ordinal_cmp(this: UOrdinal, other: UOrdinal) -> Ordering {
+pub fn jelal_ordinal_cmp(this: UOrdinal, other: UOrdinal) -> Ordering {
     let this: Ordinal = this.into();
     Ordinal::cmp(&this, other.into()).into()
 }
@@ -1313,9 +2673,49 @@ pub extern "C" fn year_new(value: IYear) -> IYear {
 #[doc = " Create a valid year and if 0, replace it with -1 ([`Self::ZERO_REPLACEMENT`] in effect)."]
 #[cfg_attr(feature = "py", pyfunction)]
 #[cfg_attr(feature = "wasm", wasm_bindgen)]
-pub fn _year_new(value: IYear) -> IYear {
+pub fn jelal_year_new(value: IYear) -> IYear {
     Year::new(value.into()).into()
 }
+#[doc = " Return the 1-based century of this year (e.g. 14 for 1300 to 1399).\n\n Negative years mirror the positive ones since there is no year 0 (-1 is the year before 1),\n so -1 to -99 is century -1, -100 to -199 is -2 and so on."]
+#[cfg(feature = "c")]
+#[unsafe(no_mangle)]
+pub extern "C" fn year_century(this: IYear) -> i32 {
+    let this: Year = this.into();
+    Year::century(&this).into()
+}
+#[doc = " Return the 1-based century of this year (e.g. 14 for 1300 to 1399).\n\n Negative years mirror the positive ones since there is no year 0 (-1 is the year before 1),\n so -1 to -99 is century -1, -100 to -199 is -2 and so on."]
+#[cfg_attr(feature = "py", pyfunction)]
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+pub fn jelal_year_century(this: IYear) -> i32 {
+    let this: Year = this.into();
+    Year::century(&this).into()
+}
+#[doc = " Return the 1-based decade of this year (e.g. 141 for 1400 to 1409).\n\n Negative years are mirrored the same way as [`Self::century`]."]
+#[cfg(feature = "c")]
+#[unsafe(no_mangle)]
+pub extern "C" fn year_decade(this: IYear) -> i32 {
+    let this: Year = this.into();
+    Year::decade(&this).into()
+}
+#[doc = " Return the 1-based decade of this year (e.g. 141 for 1400 to 1409).\n\n Negative years are mirrored the same way as [`Self::century`]."]
+#[cfg_attr(feature = "py", pyfunction)]
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+pub fn jelal_year_decade(this: IYear) -> i32 {
+    let this: Year = this.into();
+    Year::decade(&this).into()
+}
+#[doc = " The first year that [`Self::is_leap`] has calculated astronomically."]
+pub const YEAR_ASTRONOMICAL_MIN: IYear =
+    unsafe { ::core::mem::transmute(crate::Year::ASTRONOMICAL_MIN) };
+#[doc = " The first year that [`Self::is_leap`] has calculated astronomically."]
+#[unsafe(export_name = "YEAR_ASTRONOMICAL_MIN")]
+pub static _YEAR_ASTRONOMICAL_MIN: IYear = YEAR_ASTRONOMICAL_MIN;
+#[doc = " The last year that [`Self::is_leap`] has calculated astronomically."]
+pub const YEAR_ASTRONOMICAL_MAX: IYear =
+    unsafe { ::core::mem::transmute(crate::Year::ASTRONOMICAL_MAX) };
+#[doc = " The last year that [`Self::is_leap`] has calculated astronomically."]
+#[unsafe(export_name = "YEAR_ASTRONOMICAL_MAX")]
+pub static _YEAR_ASTRONOMICAL_MAX: IYear = YEAR_ASTRONOMICAL_MAX;
 #[doc = " Persian Wikipedia's list of leap years pre-calculated.\n\n NOTE Do not rely on this."]
 pub const YEAR_LEAPS_1210_TO_1500: [IYear; 71] =
     unsafe { ::core::mem::transmute(crate::Year::LEAPS_1210_TO_1500) };
@@ -1338,21 +2738,21 @@ pub extern "C" fn year_is_no_leap_correction(this: IYear) -> bool {
 #[doc = " A search into [`Self::NON_LEAP_CORRECTION`]."]
 #[cfg_attr(feature = "py", pyfunction)]
 #[cfg_attr(feature = "wasm", wasm_bindgen)]
-pub fn _year_is_no_leap_correction(this: IYear) -> bool {
+pub fn jelal_year_is_no_leap_correction(this: IYear) -> bool {
     let this: Year = this.into();
     Year::is_no_leap_correction(&this).into()
 }
-#[doc = " Is this year a leap year (366 days instead of 365).\n\n Calculated using the 33-year rule. Taken from\n <https://github.com/unicode-org/icu4x/blob/3e3da0a0a34bfe3056d0f89183270ea683f4a23c/utils/calendrical_calculations/src/persian.rs#L161C1-L173C2>"]
+#[doc = " Is this year a leap year (366 days instead of 365).\n\n Only the years from [`Self::ASTRONOMICAL_MIN`] to [`Self::ASTRONOMICAL_MAX`] are\n astronomical: a year is leap when the next new year is 366 days after its own, as calculated\n beforehand by the algorithm based on the 52.5 degrees east meridian. Outside them the\n 33-year rule is used (shifted at each end to continue the calculated years) since the\n rotation of the earth is not known well enough to calculate the new year, so those years\n are only approximate."]
 #[cfg(feature = "c")]
 #[unsafe(no_mangle)]
 pub extern "C" fn year_is_leap(this: IYear) -> bool {
     let this: Year = this.into();
     Year::is_leap(&this).into()
 }
-#[doc = " Is this year a leap year (366 days instead of 365).\n\n Calculated using the 33-year rule. Taken from\n <https://github.com/unicode-org/icu4x/blob/3e3da0a0a34bfe3056d0f89183270ea683f4a23c/utils/calendrical_calculations/src/persian.rs#L161C1-L173C2>"]
+#[doc = " Is this year a leap year (366 days instead of 365).\n\n Only the years from [`Self::ASTRONOMICAL_MIN`] to [`Self::ASTRONOMICAL_MAX`] are\n astronomical: a year is leap when the next new year is 366 days after its own, as calculated\n beforehand by the algorithm based on the 52.5 degrees east meridian. Outside them the\n 33-year rule is used (shifted at each end to continue the calculated years) since the\n rotation of the earth is not known well enough to calculate the new year, so those years\n are only approximate."]
 #[cfg_attr(feature = "py", pyfunction)]
 #[cfg_attr(feature = "wasm", wasm_bindgen)]
-pub fn _year_is_leap(this: IYear) -> bool {
+pub fn jelal_year_is_leap(this: IYear) -> bool {
     let this: Year = this.into();
     Year::is_leap(&this).into()
 }
@@ -1366,10 +2766,24 @@ pub extern "C" fn year_max_ordinal(this: IYear) -> UOrdinal {
 #[doc = " Return the number of the maximum consecutive day of the year (365 or 366 for leaps)."]
 #[cfg_attr(feature = "py", pyfunction)]
 #[cfg_attr(feature = "wasm", wasm_bindgen)]
-pub fn _year_max_ordinal(this: IYear) -> UOrdinal {
+pub fn jelal_year_max_ordinal(this: IYear) -> UOrdinal {
     let this: Year = this.into();
     Year::max_ordinal(&this).into()
 }
+#[doc = " Return the date at the given progress through this year in per mille (leap correct).\n\n 0 is the first day of the year and 1000 is the last. Greater values are clamped to 1000."]
+#[cfg(feature = "c")]
+#[unsafe(no_mangle)]
+pub extern "C" fn year_date_at_fraction(this: IYear, permille: u16) -> Date {
+    let this: Year = this.into();
+    Year::date_at_fraction(&this, permille.into()).into()
+}
+#[doc = " Return the date at the given progress through this year in per mille (leap correct).\n\n 0 is the first day of the year and 1000 is the last. Greater values are clamped to 1000."]
+#[cfg_attr(feature = "py", pyfunction)]
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+pub fn jelal_year_date_at_fraction(this: IYear, permille: u16) -> Date {
+    let this: Year = this.into();
+    Year::date_at_fraction(&this, permille.into()).into()
+}
 #[doc = " Return the owned types of this value."]
 #[cfg(feature = "c")]
 #[unsafe(no_mangle)]
@@ -1380,7 +2794,7 @@ pub extern "C" fn year_get(this: IYear) -> IYear {
 #[doc = " Return the owned types of this value."]
 #[cfg_attr(feature = "py", pyfunction)]
 #[cfg_attr(feature = "wasm", wasm_bindgen)]
-pub fn _year_get(this: IYear) -> IYear {
+pub fn jelal_year_get(this: IYear) -> IYear {
     let this: Year = this.into();
     Year::get(&this).into()
 }
@@ -1394,7 +2808,7 @@ pub extern "C" fn year_cmp(this: IYear, other: IYear) -> Ordering {
 #[doc = " Const-context definition of [`Ord::cmp`]."]
 #[cfg_attr(feature = "py", pyfunction)]
 #[cfg_attr(feature = "wasm", wasm_bindgen)]
-pub fn _year_cmp(this: IYear, other: IYear) -> Ordering {
+pub fn jelal_year_cmp(this: IYear, other: IYear) -> Ordering {
     let this: Year = this.into();
     Year::cmp(&this, other.into()).into()
 }
@@ -1409,6 +2823,12 @@ impl Year {
     #[doc = " The source of truth for the zero replacement value (-1 is before year 1, skipping 0)."]
     pub const ZERO_REPLACEMENT: Self =
         unsafe { ::core::mem::transmute(crate::Year::ZERO_REPLACEMENT) };
+    #[doc = " The first year that [`Self::is_leap`] has calculated astronomically."]
+    pub const ASTRONOMICAL_MIN: Self =
+        unsafe { ::core::mem::transmute(crate::Year::ASTRONOMICAL_MIN) };
+    #[doc = " The last year that [`Self::is_leap`] has calculated astronomically."]
+    pub const ASTRONOMICAL_MAX: Self =
+        unsafe { ::core::mem::transmute(crate::Year::ASTRONOMICAL_MAX) };
     #[doc = " Persian Wikipedia's list of leap years pre-calculated.\n\n NOTE Do not rely on this."]
     pub const LEAPS_1210_TO_1500: [Self; 71] =
         unsafe { ::core::mem::transmute(crate::Year::LEAPS_1210_TO_1500) };
@@ -1434,3 +2854,205 @@ impl Year {
         unsafe { ::core::mem::transmute(crate::Year::new(value.into())) }
     }
 }
+impl From<Season> for crate::Season {
+    fn from(value: Season) -> Self {
+        match value {
+            Season::Spring => Self::Spring,
+            Season::Summer => Self::Summer,
+            Season::Autumn => Self::Autumn,
+            Season::Winter => Self::Winter,
+        }
+    }
+}
+impl From<crate::Season> for Season {
+    fn from(value: crate::Season) -> Self {
+        match value {
+            crate::Season::Spring => Self::Spring,
+            crate::Season::Summer => Self::Summer,
+            crate::Season::Autumn => Self::Autumn,
+            crate::Season::Winter => Self::Winter,
+        }
+    }
+}
+#[doc = " All the seasons in order, starting from the season of [`Month::MIN`]."]
+pub const SEASON_SEASONS: [Season; 4] = unsafe { ::core::mem::transmute(crate::Season::SEASONS) };
+#[doc = " All the seasons in order, starting from the season of [`Month::MIN`]."]
+#[unsafe(export_name = "SEASON_SEASONS")]
+pub static _SEASON_SEASONS: [Season; 4] = SEASON_SEASONS;
+#[doc = " Return the season which the given month is in."]
+#[cfg(feature = "c")]
+#[unsafe(no_mangle)]
+pub extern "C" fn season_from_month(month: UMonth) -> Season {
+    Season::from_month(month.into()).into()
+}
+#[doc = " Return the season which the given month is in."]
+#[cfg_attr(feature = "py", pyfunction)]
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+pub fn jelal_season_from_month(month: UMonth) -> Season {
+    Season::from_month(month.into()).into()
+}
+#[cfg_attr(feature = "py", pymethods)]
+impl Season {
+    #[doc = " All the seasons in order, starting from the season of [`Month::MIN`]."]
+    pub const SEASONS: [Self; 4] = unsafe { ::core::mem::transmute(crate::Season::SEASONS) };
+}
+impl Season {
+    #[doc = " Return the season which the given month is in."]
+    pub fn from_month(month: UMonth) -> Season {
+        unsafe { ::core::mem::transmute(crate::Season::from_month(month.into())) }
+    }
+}
+#[cfg(feature = "py")]
+#[pymethods]
+impl Season {
+    #[doc = " Return the season which the given month is in."]
+    #[cfg(feature = "py")]
+    #[pyo3(name = "from_month")]
+    #[staticmethod]
+    fn __py_only_from_month(month: UMonth) -> Season {
+        unsafe { ::core::mem::transmute(crate::Season::from_month(month.into())) }
+    }
+}
+impl From<Weekday> for crate::Weekday {
+    fn from(value: Weekday) -> Self {
+        match value {
+            Weekday::Saturday => Self::Saturday,
+            Weekday::Sunday => Self::Sunday,
+            Weekday::Monday => Self::Monday,
+            Weekday::Tuesday => Self::Tuesday,
+            Weekday::Wednesday => Self::Wednesday,
+            Weekday::Thursday => Self::Thursday,
+            Weekday::Friday => Self::Friday,
+        }
+    }
+}
+impl From<crate::Weekday> for Weekday {
+    fn from(value: crate::Weekday) -> Self {
+        match value {
+            crate::Weekday::Saturday => Self::Saturday,
+            crate::Weekday::Sunday => Self::Sunday,
+            crate::Weekday::Monday => Self::Monday,
+            crate::Weekday::Tuesday => Self::Tuesday,
+            crate::Weekday::Wednesday => Self::Wednesday,
+            crate::Weekday::Thursday => Self::Thursday,
+            crate::Weekday::Friday => Self::Friday,
+        }
+    }
+}
+#[doc = " Unix Epoch in this format (Gregorian 1st of January 1970 was a Thursday)."]
+pub const WEEKDAY_EPOCH: Weekday = unsafe { ::core::mem::transmute(crate::Weekday::EPOCH) };
+#[doc = " Unix Epoch in this format (Gregorian 1st of January 1970 was a Thursday)."]
+#[unsafe(export_name = "WEEKDAY_EPOCH")]
+pub static _WEEKDAY_EPOCH: Weekday = WEEKDAY_EPOCH;
+#[doc = " The first day of the Jalali week; Saturday (Shanbe)."]
+pub const WEEKDAY_MIN: Weekday = unsafe { ::core::mem::transmute(crate::Weekday::MIN) };
+#[doc = " The first day of the Jalali week; Saturday (Shanbe)."]
+#[unsafe(export_name = "WEEKDAY_MIN")]
+pub static _WEEKDAY_MIN: Weekday = WEEKDAY_MIN;
+#[doc = " The last day of the Jalali week; Friday (Jomeh)."]
+pub const WEEKDAY_MAX: Weekday = unsafe { ::core::mem::transmute(crate::Weekday::MAX) };
+#[doc = " The last day of the Jalali week; Friday (Jomeh)."]
+#[unsafe(export_name = "WEEKDAY_MAX")]
+pub static _WEEKDAY_MAX: Weekday = WEEKDAY_MAX;
+#[doc = " All the days of the week in order, starting from [`Self::MIN`]."]
+pub const WEEKDAY_WEEKDAYS: [Weekday; 7] =
+    unsafe { ::core::mem::transmute(crate::Weekday::WEEKDAYS) };
+#[doc = " All the days of the week in order, starting from [`Self::MIN`]."]
+#[unsafe(export_name = "WEEKDAY_WEEKDAYS")]
+pub static _WEEKDAY_WEEKDAYS: [Weekday; 7] = WEEKDAY_WEEKDAYS;
+#[doc = " Return the weekday which is the given number of days away from this one."]
+#[cfg(feature = "c")]
+#[unsafe(no_mangle)]
+pub extern "C" fn weekday_add_days(this: Weekday, days: IDayDiff) -> Weekday {
+    Weekday::add_days(this.into(), days.into()).into()
+}
+#[doc = " Return the weekday which is the given number of days away from this one."]
+#[cfg_attr(feature = "py", pyfunction)]
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+pub fn jelal_weekday_add_days(this: Weekday, days: IDayDiff) -> Weekday {
+    Weekday::add_days(this.into(), days.into()).into()
+}
+#[doc = " Return the day of the week as in `tm_wday` of C's `struct tm` (0 to 6, from Sunday)."]
+#[cfg(feature = "c")]
+#[unsafe(no_mangle)]
+pub extern "C" fn weekday_to_c_wday(this: Weekday) -> u8 {
+    Weekday::to_c_wday(&this.into()).into()
+}
+#[doc = " Return the day of the week as in `tm_wday` of C's `struct tm` (0 to 6, from Sunday)."]
+#[cfg_attr(feature = "py", pyfunction)]
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+pub fn jelal_weekday_to_c_wday(this: Weekday) -> u8 {
+    Weekday::to_c_wday(&this.into()).into()
+}
+#[doc = " Return how many days are passed since the given start of the week (0 to 6)."]
+#[cfg(feature = "c")]
+#[unsafe(no_mangle)]
+pub extern "C" fn weekday_days_since(this: Weekday, start: WeekStart) -> u8 {
+    Weekday::days_since(&this.into(), start.into()).into()
+}
+#[doc = " Return how many days are passed since the given start of the week (0 to 6)."]
+#[cfg_attr(feature = "py", pyfunction)]
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+pub fn jelal_weekday_days_since(this: Weekday, start: WeekStart) -> u8 {
+    Weekday::days_since(&this.into(), start.into()).into()
+}
+#[cfg_attr(feature = "py", pymethods)]
+impl Weekday {
+    #[doc = " Unix Epoch in this format (Gregorian 1st of January 1970 was a Thursday)."]
+    pub const EPOCH: Self = unsafe { ::core::mem::transmute(crate::Weekday::EPOCH) };
+    #[doc = " The first day of the Jalali week; Saturday (Shanbe)."]
+    pub const MIN: Self = unsafe { ::core::mem::transmute(crate::Weekday::MIN) };
+    #[doc = " The last day of the Jalali week; Friday (Jomeh)."]
+    pub const MAX: Self = unsafe { ::core::mem::transmute(crate::Weekday::MAX) };
+    #[doc = " All the days of the week in order, starting from [`Self::MIN`]."]
+    pub const WEEKDAYS: [Self; 7] = unsafe { ::core::mem::transmute(crate::Weekday::WEEKDAYS) };
+}
+impl Weekday {
+    #[doc = " Return the weekday which is the given number of days away from this one."]
+    pub fn add_days(self, days: IDayDiff) -> Weekday {
+        let this = self;
+        let this: crate::Weekday = this.into();
+        unsafe { ::core::mem::transmute(crate::Weekday::add_days(this, days.into())) }
+    }
+}
+#[cfg(feature = "py")]
+#[pymethods]
+impl Weekday {
+    #[doc = " Return the weekday which is the given number of days away from this one."]
+    #[cfg(feature = "py")]
+    #[pyo3(name = "add_days")]
+    fn __py_only_add_days(&self, days: IDayDiff) -> Weekday {
+        let this = self;
+        let this: Self = this.clone();
+        let this: crate::Weekday = this.into();
+        unsafe { ::core::mem::transmute(crate::Weekday::add_days(this, days.into())) }
+    }
+}
+#[doc = " FFI version of an `Eq` trait implementation"]
+#[cfg(feature = "c")]
+#[unsafe(no_mangle)]
+pub extern "C" fn monthday_eq(this: &MonthDay, other: &MonthDay) -> bool {
+    crate::MonthDay::from(this.clone()) == crate::MonthDay::from(other.clone())
+}
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+impl MonthDay {
+    #[doc = " FFI version of an `Eq` trait implementation"]
+    # [cfg_attr (feature = "wasm" , wasm_bindgen (js_name = equals))]
+    pub fn ext_eq(&self, other: &Self) -> bool {
+        crate::MonthDay::from(self.clone()) == crate::MonthDay::from(other.clone())
+    }
+}
+#[doc = " FFI version of an `Eq` trait implementation"]
+#[cfg(feature = "c")]
+#[unsafe(no_mangle)]
+pub extern "C" fn date_eq(this: &Date, other: &Date) -> bool {
+    crate::Date::from(this.clone()) == crate::Date::from(other.clone())
+}
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+impl Date {
+    #[doc = " FFI version of an `Eq` trait implementation"]
+    # [cfg_attr (feature = "wasm" , wasm_bindgen (js_name = equals))]
+    pub fn ext_eq(&self, other: &Self) -> bool {
+        crate::Date::from(self.clone()) == crate::Date::from(other.clone())
+    }
+}
@@ -32,6 +32,7 @@ pub use generated::*;
 /// - [`Self::tm_mon`]: represents month (0-11) where 0 is the first month of the year.
 /// - [`Self::tm_yday`]: represents the ordinal where 0 is the first day of the year.
 /// - [`Self::tm_mday`]: exactly as in `jelal`.
+/// - [`Self::tm_wday`]: exactly as in C (0-6) where 0 is Sunday.
 ///
 /// The rest are unsupported (at least for now) and will default to 0.
 ///
@@ -58,9 +59,7 @@ pub struct tm {
     /// For normal calculations this is often deducted by 1900.  In Jalali, one may ignore the -1900
     /// offset which makes it [`crate::Year`] if not zero.
     pub tm_year: c_int,
-    /// The day of the week.
-    ///
-    /// As of now, this is not set in by `jelal`, defaults to 0.
+    /// The day of the week (0-6) where 0 is Sunday, see [`crate::Weekday::to_c_wday`].
     pub tm_wday: c_int,
     /// The ordinal, day of year indexed from 0, equal to [`crate::Ordinal`] - 1.
     pub tm_yday: c_int,
@@ -72,6 +71,56 @@ pub struct tm {
     pub tm_zone: *const c_char,
 }
 
+/// Write the text of `value` to `buf` as a null-terminated string and return the text's length.
+///
+/// Like `snprintf`, at most `len - 1` bytes of text are written (cut if needed) and the returned
+/// length does not count the null byte, so a result of `len` or more means the text was cut. Nothing
+/// is written if `buf` is null or `len` is 0.
+///
+/// # Safety
+/// `buf` must be null or valid for writing `len` bytes.
+#[cfg(feature = "c")]
+pub(crate) unsafe fn write_display(
+    value: &impl core::fmt::Display,
+    buf: *mut c_char,
+    len: usize,
+) -> usize {
+    /// Write to the buffer while saving the last byte for null and count the whole text.
+    struct Writer<'a> {
+        buf: &'a mut [u8],
+        written: usize,
+        total: usize,
+    }
+
+    impl core::fmt::Write for Writer<'_> {
+        fn write_str(&mut self, s: &str) -> core::fmt::Result {
+            let room = self.buf.len().saturating_sub(self.written + 1);
+            let n = room.min(s.len());
+            self.buf[self.written..self.written + n].copy_from_slice(&s.as_bytes()[..n]);
+            self.written += n;
+            self.total += s.len();
+            Ok(())
+        }
+    }
+
+    let buf: &mut [u8] = if buf.is_null() {
+        &mut []
+    } else {
+        unsafe { core::slice::from_raw_parts_mut(buf.cast(), len) }
+    };
+    let mut writer = Writer {
+        buf,
+        written: 0,
+        total: 0,
+    };
+    // the writer never fails and neither does the `Display` of this crate
+    let _ = core::fmt::write(&mut writer, format_args!("{value}"));
+    if let Some(end) = writer.buf.get_mut(writer.written) {
+        *end = 0;
+    }
+    writer.total
+}
+
 #[cfg(feature = "c")]
 impl tm {
     /// Create a default (invalid) value.
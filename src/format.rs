@@ -0,0 +1,131 @@
+//! Holds the customizable formatting of the dates.
+
+use core::fmt::{Display, Write};
+#[cfg(feature = "std")]
+use std::string::String;
+
+use crate::{Date, MonthDay};
+
+/// Display a date as `year/month/day` with a zero-padded month and day (e.g. `1404/02/03`).
+///
+/// The `Display` of [`Date`] itself is not padded (e.g. `1404/2/3`).
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Padded(pub Date);
+
+impl Display for Padded {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let month_day = MonthDay::from_ordinal(self.0.ordinal);
+        write!(
+            f,
+            "{}/{:02}/{:02}",
+            self.0.year, month_day.month, month_day.day
+        )
+    }
+}
+
+/// Display a month and day as the day and the name of the month (e.g. `۱۳ اردیبهشت`).
+///
+/// See [`MonthDay::name`] and [`MonthDay::name_latin`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct MonthDayName {
+    /// The value to display.
+    month_day: MonthDay,
+    /// Whether to use the Latin digits and the romanized name (e.g. `13 Ordibehesht`).
+    latin: bool,
+}
+
+impl Display for MonthDayName {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let (month, day) = (self.month_day.month, self.month_day.day);
+        if self.latin {
+            return write!(f, "{} {}", day, month.name_latin());
+        }
+
+        // the day has at most 2 digits
+        if day >= 10 {
+            f.write_char(persian_digit(day / 10))?;
+        }
+        f.write_char(persian_digit(day % 10))?;
+        write!(f, " {}", month.name())
+    }
+}
+
+/// Return the Persian (Extended Arabic-Indic) form of the given digit (0 to 9).
+const fn persian_digit(digit: u8) -> char {
+    // the digits are consecutive from U+06F0 and the input is at most 9
+    match char::from_u32('۰' as u32 + digit as u32) {
+        Some(c) => c,
+        None => '۰',
+    }
+}
+
+// Not in the main `impl` block since these types are not available to every FFI.
+impl MonthDay {
+    /// Return a wrapper which displays the day in Persian digits and the Persian month name.
+    ///
+    /// For example `۱۳ اردیبهشت` for the 13th of the second month (see [`crate::Month::name`]).
+    pub const fn name(&self) -> MonthDayName {
+        MonthDayName {
+            month_day: MonthDay::new(self.month, self.day),
+            latin: false,
+        }
+    }
+
+    /// Return a wrapper which displays the day and the romanized month name.
+    ///
+    /// For example `13 Ordibehesht` for the 13th of the second month (see
+    /// [`crate::Month::name_latin`]).
+    pub const fn name_latin(&self) -> MonthDayName {
+        MonthDayName {
+            month_day: MonthDay::new(self.month, self.day),
+            latin: true,
+        }
+    }
+}
+
+// Not in the main `impl` block since these types are not available to every FFI.
+impl Date {
+    /// Return a wrapper which displays this date with a zero-padded month and day.
+    ///
+    /// See [`Padded`].
+    pub const fn padded(&self) -> Padded {
+        Padded(Self::new(self.year, self.ordinal))
+    }
+
+    /// Format this date with the given pattern of `strftime`-like tokens.
+    ///
+    /// - `%Y`: year (e.g. `1404`)
+    /// - `%m`: zero-padded month (e.g. `02`)
+    /// - `%d`: zero-padded day of the month (e.g. `03`)
+    /// - `%j`: day of the year (e.g. `34`)
+    /// - `%B`: Persian name of the month (see [`crate::Month::name`])
+    /// - `%A`: Persian name of the day of the week (see [`crate::Weekday::name`])
+    /// - `%%`: a literal `%`
+    ///
+    /// Any other token (and a trailing `%`) is written as is.
+    #[cfg(feature = "std")]
+    pub fn format(&self, pattern: &str) -> String {
+        let month_day = MonthDay::from_ordinal(self.ordinal);
+        let mut out = String::with_capacity(pattern.len());
+        let mut chars = pattern.chars();
+        while let Some(c) = chars.next() {
+            if c != '%' {
+                out.push(c);
+                continue;
+            }
+            // writing into a `String` never fails
+            let _ = match chars.next() {
+                Some('Y') => write!(out, "{}", self.year),
+                Some('m') => write!(out, "{:02}", month_day.month),
+                Some('d') => write!(out, "{:02}", month_day.day),
+                Some('j') => write!(out, "{}", self.ordinal),
+                Some('B') => write!(out, "{}", month_day.month.name()),
+                Some('A') => write!(out, "{}", self.weekday().name()),
+                Some('%') => write!(out, "%"),
+                Some(other) => write!(out, "%{}", other),
+                None => write!(out, "%"),
+            };
+        }
+        out
+    }
+}
@@ -0,0 +1,182 @@
+//! Holds the precalculated leap years and the 33-year rule which continues them.
+//!
+//! These are kept out of the `primitive` module since `codegen` copies its constants to the FFI.
+
+use crate::{IYear, Year};
+
+/// The leap years from [`Year::ASTRONOMICAL_MIN`] to [`Year::ASTRONOMICAL_MAX`] as bits.
+///
+/// The bit of a year is its [`astronomical_index`] (bit `i % 64` of the item `i / 64`). These are
+/// calculated by the astronomical algorithm based on the 52.5 degrees east meridian which is too
+/// slow to run on each call (see `test_is_leap_matches_astronomy` to check it).
+const ASTRONOMICAL_LEAPS: [u64; 94] = [
+    0x8888888844444444,
+    0x2222222211111110,
+    0x8888888844444444,
+    0x2222222211111110,
+    0x8888888844444444,
+    0x2222222211111110,
+    0x8888888844444444,
+    0x2222222111111110,
+    0x8888888444444442,
+    0x2222222111111110,
+    0x8888888444444442,
+    0x2222222111111110,
+    0x8888888444444442,
+    0x2222221111111110,
+    0x8888888444444422,
+    0x2222221111111108,
+    0x8888888444444422,
+    0x2222221111111108,
+    0x8888888444444422,
+    0x2222211111111088,
+    0x8888884444444422,
+    0x2222211111111088,
+    0x8888884444444422,
+    0x2222211111111088,
+    0x8888844444444222,
+    0x2222211111110888,
+    0x8888844444444222,
+    0x2222111111110888,
+    0x8888444444442222,
+    0x2222111111108888,
+    0x8888444444442222,
+    0x2221111111108888,
+    0x8884444444422222,
+    0x2221111111088888,
+    0x8884444444422222,
+    0x2211111111088888,
+    0x8844444444222222,
+    0x2211111110888888,
+    0x8844444444222222,
+    0x2111111110888888,
+    0x8444444442222222,
+    0x2111111108888888,
+    0x8444444442222222,
+    0x1111111088888888,
+    0x4444444422222222,
+    0x1111111088888888,
+    0x4444444222222221,
+    0x1111110888888884,
+    0x4444444222222211,
+    0x1111108888888884,
+    0x4444442222222211,
+    0x1111108888888844,
+    0x4444422222222111,
+    0x1111088888888444,
+    0x4444422222221111,
+    0x1110888888884444,
+    0x4444222222221111,
+    0x1110888888884444,
+    0x4442222222211111,
+    0x1108888888844444,
+    0x4422222222111111,
+    0x1088888888444444,
+    0x4222222221111111,
+    0x0888888888444444,
+    0x4222222211111111,
+    0x0888888884444444,
+    0x2222222211111111,
+    0x8888888844444444,
+    0x2222222111111110,
+    0x8888888444444442,
+    0x2222221111111108,
+    0x8888884444444422,
+    0x2222211111111088,
+    0x8888844444444222,
+    0x2222211111111088,
+    0x8888844444442222,
+    0x2222111111110888,
+    0x8888444444442222,
+    0x2221111111108888,
+    0x8884444444422222,
+    0x2211111111088888,
+    0x8844444444222222,
+    0x2211111111088888,
+    0x8444444444222222,
+    0x2111111110888888,
+    0x8444444442222222,
+    0x1111111108888888,
+    0x4444444422222222,
+    0x1111111108888888,
+    0x4444444422222222,
+    0x1111111088888884,
+    0x4444444222222221,
+    0x1111110888888884,
+    0x0000444222222221,
+];
+
+/// The shift of the 33-year rule before [`Year::ASTRONOMICAL_MIN`] to continue its leap years.
+const PRE_ASTRONOMICAL_SHIFT: i64 = 7;
+
+/// The shift of the 33-year rule after [`Year::ASTRONOMICAL_MAX`] to continue its leap years.
+const POST_ASTRONOMICAL_SHIFT: i64 = 24;
+
+/// Return the index of an astronomically calculated year in [`ASTRONOMICAL_LEAPS`].
+const fn astronomical_index(year: IYear) -> usize {
+    // the years without the gap of 0
+    let index = if year.is_negative() { year + 1 } else { year };
+    (index - Year::ASTRONOMICAL_MIN.0 - 1) as usize
+}
+
+/// Is the year leap by the 33-year rule shifted by the given years.
+const fn is_leap_33(year: i64, shift: i64) -> bool {
+    (25 * (year + shift) + 11).rem_euclid(33) < 8
+}
+
+/// Count the leap years from `from` to `to` (exclusive) by the 33-year rule shifted by `shift`.
+const fn count_leaps_33(from: i64, to: i64, shift: i64) -> i64 {
+    // each 33 consecutive years have 8 leap years
+    let cycles = (to - from) / 33;
+    let mut count = cycles * 8;
+    let mut year = from + cycles * 33;
+    while year < to {
+        if is_leap_33(year, shift) {
+            count += 1;
+        }
+        year += 1;
+    }
+    count
+}
+
+/// Is the year leap (see [`Year::is_leap`]).
+pub(crate) const fn is_leap(year: IYear) -> bool {
+    if year < Year::ASTRONOMICAL_MIN.0 {
+        is_leap_33(year as i64, PRE_ASTRONOMICAL_SHIFT)
+    } else if year > Year::ASTRONOMICAL_MAX.0 {
+        is_leap_33(year as i64, POST_ASTRONOMICAL_SHIFT)
+    } else {
+        let index = astronomical_index(year);
+        ASTRONOMICAL_LEAPS[index / 64] >> (index % 64) & 1 == 1
+    }
+}
+
+/// Return the number of leap years from [`Year::ASTRONOMICAL_MIN`] to the year at `index`.
+///
+/// The index of a year counts the years without the gap of 0 (`0` for year -1 and `1` for year 1)
+/// and the year at `index` itself is not counted. The years before [`Year::ASTRONOMICAL_MIN`]
+/// are counted as negative. Any index from [`IYear::MIN`] to one after [`IYear::MAX`] is valid.
+pub(crate) const fn leaps_before_index(index: i64) -> i64 {
+    let min = Year::ASTRONOMICAL_MIN.0 as i64;
+    // the index after the last astronomically calculated year
+    let end = Year::ASTRONOMICAL_MAX.0 as i64 + 1;
+    if index <= min {
+        // the years are one less than their indexes here
+        return -count_leaps_33(index - 1, min, PRE_ASTRONOMICAL_SHIFT);
+    }
+
+    let bits = (if index < end { index } else { end } - min - 1) as usize;
+    let mut count = 0;
+    let mut i = 0;
+    while i < bits / 64 {
+        count += ASTRONOMICAL_LEAPS[i].count_ones() as i64;
+        i += 1;
+    }
+    if bits % 64 != 0 {
+        count += (ASTRONOMICAL_LEAPS[i] & ((1 << (bits % 64)) - 1)).count_ones() as i64;
+    }
+    if index > end {
+        count += count_leaps_33(end, index, POST_ASTRONOMICAL_SHIFT);
+    }
+    count
+}
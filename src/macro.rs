@@ -18,38 +18,67 @@
 /// implementations. Since `i32` is basically the default unsuffixed type for numbers (or it seems
 /// so without investigating), automatic `i32` implementations are important for a seemless usage of
 /// the transparent types.
-//
-// TODO add tests for each generated MIN and MAX to make sure "as X" used so frequently won't
-// overflow or else.
+///
+/// Any tokens to `skip_add_strict` will skip the automatic `add_strict` which must be implemented
+/// manually then (for the types with invalid values between their limits).
 macro_rules! int_wrapper {
     (
         ident: $ident:ident,
         signed: $signed:ident,
         $(unsigned: $unsigned:ident,)?
         $(skip_i32_helpers: $skip_i32_helpers:tt,)?
+        $(skip_add_strict: $skip_add_strict:tt,)?
     ) => {
-        impl $ident {
-            /// Add another value to this, also ensure its valid and if this would fail normally.
-            ///
-            /// If the normal calculation of results would produce and invalid instance, this will
-            /// return true.
-            #[must_use]
-            pub const fn add_strict(self, rhs: $signed) -> DidSaturate<Self> {
-                match int_wrapper!(
-                    if $($unsigned)? {
-                        self.0.checked_add_signed(rhs)
-                    } else {
-                        self.0.checked_add(rhs)
-                    }
-                ){
-                    Some(v) => {
-                        let result = Self::new(v);
-                        DidSaturate::new(result.0 != v, result)
+        // This might be implemented differently so this is a way to skip it.
+        int_wrapper!(
+            if $($skip_add_strict)? {
+            } else {
+                impl $ident {
+                    /// Add another value to this, also ensure its valid and if this would fail
+                    /// normally.
+                    ///
+                    /// If the normal calculation of results would produce and invalid instance,
+                    /// this will return true.
+                    #[must_use]
+                    pub const fn add_strict(self, rhs: $signed) -> DidSaturate<Self> {
+                        match int_wrapper!(
+                            if $($unsigned)? {
+                                self.0.checked_add_signed(rhs)
+                            } else {
+                                self.0.checked_add(rhs)
+                            }
+                        ){
+                            Some(v) => {
+                                let result = Self::new(v);
+                                DidSaturate::new(result.0 != v, result)
+                            }
+                            None if rhs.is_negative() => DidSaturate::saturated(Self::MIN),
+                            None => DidSaturate::saturated(Self::MAX),
+                        }
                     }
-                    None if rhs.is_negative() => DidSaturate::saturated(Self::MIN),
-                    None => DidSaturate::saturated(Self::MAX),
                 }
             }
+        );
+
+        impl $ident {
+            /// Add another value to this and saturate at the limits (same as `+`).
+            #[must_use]
+            pub const fn saturating_add(self, rhs: $signed) -> Self {
+                self.add_strict(rhs).result
+            }
+
+            /// Add another value to this and return whether the result was saturated (see
+            /// `add_strict`).
+            #[must_use]
+            pub const fn overflowing_add(self, rhs: $signed) -> (Self, bool) {
+                let v = self.add_strict(rhs);
+                (v.result, v.did_saturate)
+            }
+
+            /// Const-context definition of [`PartialEq::eq`].
+            pub const fn eq(&self, other: &Self) -> bool {
+                self.0 == other.0
+            }
         }
 
         impl core::fmt::Display for $ident {
@@ -58,6 +87,21 @@ macro_rules! int_wrapper {
             }
         }
 
+        #[cfg(feature = "serde")]
+        impl ::serde::Serialize for $ident {
+            fn serialize<S: ::serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                self.0.serialize(serializer)
+            }
+        }
+
+        /// Deserialize the inner value and saturate it using `new`.
+        #[cfg(feature = "serde")]
+        impl<'de> ::serde::Deserialize<'de> for $ident {
+            fn deserialize<D: ::serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                <int_wrapper!($($unsigned)? or $signed)>::deserialize(deserializer).map(Self::new)
+            }
+        }
+
         impl core::ops::Deref for $ident {
             type Target = int_wrapper!(
                 if $($unsigned)? { $($unsigned)? } else { $signed }
@@ -192,3 +236,23 @@ macro_rules! cmp {
         }
     };
 }
+
+/// Assert the dates that are the given number of days away from [`crate::Date::EPOCH`] in tests.
+///
+/// Each `days => (year, month, day)` is checked after adding `offset` to `days` and its ordinal must
+/// equal `ordinal + days`.
+#[cfg(test)]
+macro_rules! jelal_test_epoch {
+    (
+        offset: $offset:expr,
+        ordinal: $ordinal:expr,
+        $($days:literal => ($y:expr, $m:expr, $d:expr)),+ $(,)?
+    ) => {
+        $({
+            let days: $crate::IDayDiff = $days;
+            let v = $crate::Date::EPOCH.add_days_strict($offset + days).result;
+            assert_eq!(v.ordinal().get() as $crate::IDayDiff, $ordinal + days);
+            assert_eq!(v, $crate::Date::from(($y, $m, $d)));
+        })+
+    };
+}
@@ -4,7 +4,7 @@
 
 use core::cmp::Ordering;
 
-use crate::utility::DidSaturate;
+use crate::{Date, MonthDay, Season, error::DateError, leap, utility::DidSaturate};
 
 /// Counts consecutive days for addition and subtraction operations.
 pub type IDayDiff = i32;
@@ -42,7 +42,7 @@ pub type IOrdinal = i16;
 pub type IYear = i32;
 
 /// Holds valid months count.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 #[repr(transparent)]
 pub struct Month(pub(crate) UMonth);
 
@@ -65,6 +65,27 @@ impl Month {
     /// The last month of the Jalali year; 12: Esfand.
     pub const MAX: Self = Self(12);
 
+    /// All the months of the Jalali year in order (Farvardin to Esfand).
+    pub const MONTHS: [Self; 12] = [
+        Self(1),
+        Self(2),
+        Self(3),
+        Self(4),
+        Self(5),
+        Self(6),
+        Self(7),
+        Self(8),
+        Self(9),
+        Self(10),
+        Self(11),
+        Self(12),
+    ];
+
+    /// Iterate over all the months of the year in order (see [`Self::MONTHS`]).
+    pub fn iter() -> impl Iterator<Item = Self> {
+        Self::MONTHS.into_iter()
+    }
+
     /// Convert a valid month to ordinal assuming 0th day of the month (-1) if month is valid.
     pub const fn to_ordinal_assume_zero(&self) -> Ordinal {
         let zm = self.0 as UOrdinal - 1;
@@ -86,6 +107,76 @@ impl Month {
         }
     }
 
+    /// Return the Persian name of this month (e.g. "فروردین" for [`Self::MIN`]).
+    pub const fn name(&self) -> &'static str {
+        const NAMES: [&str; 12] = [
+            "فروردین",
+            "اردیبهشت",
+            "خرداد",
+            "تیر",
+            "مرداد",
+            "شهریور",
+            "مهر",
+            "آبان",
+            "آذر",
+            "دی",
+            "بهمن",
+            "اسفند",
+        ];
+        NAMES[(self.0 - Self::MIN.0) as usize]
+    }
+
+    /// Return the romanized Persian name of this month (e.g. "Farvardin" for [`Self::MIN`]).
+    pub const fn name_latin(&self) -> &'static str {
+        const NAMES: [&str; 12] = [
+            "Farvardin",
+            "Ordibehesht",
+            "Khordad",
+            "Tir",
+            "Mordad",
+            "Shahrivar",
+            "Mehr",
+            "Aban",
+            "Azar",
+            "Dey",
+            "Bahman",
+            "Esfand",
+        ];
+        NAMES[(self.0 - Self::MIN.0) as usize]
+    }
+
+    /// Return the number of days in this month of the given year (leap correct).
+    pub const fn days_in(&self, year: Year) -> UMonthDay {
+        if self.0 < Self::MID.0 {
+            MonthDay::MAX_DAY
+        } else if self.0 < Self::MAX.0 {
+            MonthDay::POST_MID_MAX_DAY
+        } else if year.is_leap() {
+            MonthDay::LEAP_LAST_MAX_DAY
+        } else {
+            MonthDay::NON_LEAP_LAST_MAX_DAY
+        }
+    }
+
+    /// Return the season which this month is in.
+    pub const fn season(&self) -> Season {
+        Season::from_month(*self)
+    }
+
+    /// Add months to this and wrap around the year, returning the years passed (e.g. 12 + 1 is 1
+    /// and 1 year).
+    ///
+    /// Unlike `+` (see `saturating_add`), this does not stop at [`Self::MIN`] and [`Self::MAX`].
+    /// The years are negative when wrapped toward past (e.g. 1 - 1 is 12 and -1 year).
+    #[must_use]
+    pub const fn wrapping_add(self, rhs: IMonth) -> (Self, i32) {
+        let months = (self.0 - Self::MIN.0) as i32 + rhs as i32;
+        (
+            Self((months.rem_euclid(12) + Self::MIN.0 as i32) as UMonth),
+            months.div_euclid(12),
+        )
+    }
+
     /// Return the owned types of this value.
     pub const fn get(&self) -> UMonth {
         self.0
@@ -109,6 +200,13 @@ impl Ord for Month {
     }
 }
 
+impl Default for Month {
+    /// Return the first month of the year ([`Self::MIN`]).
+    fn default() -> Self {
+        Self::MIN
+    }
+}
+
 impl From<Month> for Ordinal {
     fn from(value: Month) -> Self {
         value.to_ordinal_assume_zero()
@@ -116,7 +214,7 @@ impl From<Month> for Ordinal {
 }
 
 /// A value representing a day of a year in a leap year.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 #[repr(transparent)]
 pub struct Ordinal(pub(crate) UOrdinal);
 
@@ -153,6 +251,31 @@ impl Ordinal {
         }
     }
 
+    /// Convert this day of the year to its month and day (leap correct).
+    ///
+    /// This is the reverse of [`MonthDay::to_ordinal`], for example `Ordinal::new(44)` is the 13th
+    /// of the second month (`2/13`) and [`Self::MAX`] is the 30th of the last.
+    pub const fn to_month_day(&self) -> MonthDay {
+        MonthDay::from_ordinal(*self)
+    }
+
+    /// Is this day in the first half of the year (before [`Self::MID`]).
+    ///
+    /// The first half ends on day 186 which is the 31st of the sixth month.
+    pub const fn is_first_half(&self) -> bool {
+        self.0 < Self::MID.0
+    }
+
+    /// Is this day in the second half of the year (on or after [`Self::MID`]).
+    pub const fn is_second_half(&self) -> bool {
+        !self.is_first_half()
+    }
+
+    /// Return the half of the year this day is in, 1 or 2 (see [`Self::is_first_half`]).
+    pub const fn half(&self) -> u8 {
+        if self.is_first_half() { 1 } else { 2 }
+    }
+
     /// Return the owned types of this value.
     pub const fn get(&self) -> UOrdinal {
         self.0
@@ -176,10 +299,17 @@ impl Ord for Ordinal {
     }
 }
 
+impl Default for Ordinal {
+    /// Return the first day of the year ([`Self::MIN`]).
+    fn default() -> Self {
+        Self::MIN
+    }
+}
+
 // TODO rename impl_new to new_strict and implement new off of it.
 
 /// The base year counter type for Jalali calendar (no 0 variant).
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 #[repr(transparent)]
 pub struct Year(pub(crate) IYear);
 
@@ -187,8 +317,29 @@ int_wrapper!(
     ident: Year,
     signed: IYear,
     skip_i32_helpers: true,
+    skip_add_strict: true,
 );
 
+/// Const-context definition of [`slice::binary_search`] for sorted years.
+pub(crate) const fn binary_search_years(years: &[Year], year: &Year) -> Result<usize, usize> {
+    let (mut low, mut high) = (0, years.len());
+    while low < high {
+        let mid = low + (high - low) / 2;
+        match years[mid].cmp(year) {
+            Ordering::Less => low = mid + 1,
+            Ordering::Greater => high = mid,
+            Ordering::Equal => return Ok(mid),
+        }
+    }
+    Err(low)
+}
+
+/// Return the 1-based group of `size` years that `year` falls into, keeping the sign.
+const fn year_group(year: IYear, size: u32) -> i32 {
+    let group = (year.unsigned_abs() / size + 1) as i32;
+    if year < 0 { -group } else { group }
+}
+
 impl Year {
     /// Unix Epoch in this format (equivalent to Gregorian 1970).
     pub const EPOCH: Self = Self(1348);
@@ -211,6 +362,62 @@ impl Year {
         }
     }
 
+    /// Add another value to this, skipping over 0 (e.g. -1 + 1 is 1), and saturate at limits.
+    ///
+    /// If the normal calculation of results would produce and invalid instance, this will return
+    /// true.
+    #[must_use]
+    pub const fn add_strict(self, rhs: IYear) -> DidSaturate<Self> {
+        // there is no year 0 so passing over it (or landing on it) is one more year away
+        let rhs = rhs as i64;
+        let v = self.0 as i64 + rhs;
+        let v = if self.0 > 0 && v <= 0 {
+            v - 1
+        } else if self.0 < 0 && v >= 0 {
+            v + 1
+        } else {
+            v
+        };
+
+        if v > IYear::MAX as i64 {
+            DidSaturate::saturated(Self::MAX)
+        } else if v < IYear::MIN as i64 {
+            DidSaturate::saturated(Self::MIN)
+        } else {
+            DidSaturate::not_saturated(Self(v as IYear))
+        }
+    }
+
+    /// Create a valid year or return an error if 0 (instead of replacing it like [`Self::new`]).
+    pub const fn from_raw_checked(value: IYear) -> Result<Self, DateError> {
+        if value == 0 {
+            Err(DateError::YearZero)
+        } else {
+            Ok(Self(value))
+        }
+    }
+
+    /// Return the 1-based century of this year (e.g. 14 for 1300 to 1399).
+    ///
+    /// Negative years mirror the positive ones since there is no year 0 (-1 is the year before 1),
+    /// so -1 to -99 is century -1, -100 to -199 is -2 and so on.
+    pub const fn century(&self) -> i32 {
+        year_group(self.0, 100)
+    }
+
+    /// Return the 1-based decade of this year (e.g. 141 for 1400 to 1409).
+    ///
+    /// Negative years are mirrored the same way as [`Self::century`].
+    pub const fn decade(&self) -> i32 {
+        year_group(self.0, 10)
+    }
+
+    /// The first year that [`Self::is_leap`] has calculated astronomically.
+    pub const ASTRONOMICAL_MIN: Self = Self(-2000);
+
+    /// The last year that [`Self::is_leap`] has calculated astronomically.
+    pub const ASTRONOMICAL_MAX: Self = Self(4000);
+
     /// Persian Wikipedia's list of leap years pre-calculated.
     ///
     /// NOTE Do not rely on this.
@@ -236,8 +443,8 @@ impl Year {
     ///
     /// Taken from
     /// <https://github.com/unicode-org/icu4x/blob/3e3da0a0a34bfe3056d0f89183270ea683f4a23c/utils/calendrical_calculations/src/persian.rs#L23>
-    // TODO make a generalized algorithmic implementation
-    // TODO fix cbindgen ignoring this
+    //
+    // This is no longer used by `is_leap` and is kept to test the astronomical algorithm.
     // keep it semi-clean
     pub const NON_LEAP_CORRECTION: [Self; 78] = unsafe {
         core::mem::transmute([
@@ -252,45 +459,60 @@ impl Year {
 
     /// A search into [`Self::NON_LEAP_CORRECTION`].
     pub const fn is_no_leap_correction(&self) -> bool {
-        // HACK(const): binary_search not in const: NON_LEAP_CORRECTION.binary_search(&year).is_ok()
-        if self.cmp(&Self::NON_LEAP_CORRECTION[0]).is_lt()
-            || self
-                .cmp(&Self::NON_LEAP_CORRECTION[Self::NON_LEAP_CORRECTION.len() - 1])
-                .is_gt()
-        {
-            return false;
-        }
-
-        let mut i = 0;
-        while i < Self::NON_LEAP_CORRECTION.len() {
-            if self.cmp(&Self::NON_LEAP_CORRECTION[i]).is_eq() {
-                return true;
-            }
-            i += 1;
-        }
-        false
+        binary_search_years(&Self::NON_LEAP_CORRECTION, self).is_ok()
     }
 
     /// Is this year a leap year (366 days instead of 365).
     ///
-    /// Calculated using the 33-year rule. Taken from
-    /// <https://github.com/unicode-org/icu4x/blob/3e3da0a0a34bfe3056d0f89183270ea683f4a23c/utils/calendrical_calculations/src/persian.rs#L161C1-L173C2>
+    /// Only the years from [`Self::ASTRONOMICAL_MIN`] to [`Self::ASTRONOMICAL_MAX`] are
+    /// astronomical: a year is leap when the next new year is 366 days after its own, as calculated
+    /// beforehand by the algorithm based on the 52.5 degrees east meridian. Outside them the
+    /// 33-year rule is used (shifted at each end to continue the calculated years) since the
+    /// rotation of the earth is not known well enough to calculate the new year, so those years
+    /// are only approximate.
     pub const fn is_leap(&self) -> bool {
-        if self.cmp(&Self::NON_LEAP_CORRECTION[0]).is_ge() && self.is_no_leap_correction() {
-            return false;
-        }
+        leap::is_leap(self.0)
+    }
 
-        let prev = self.add_strict(-1);
-        // no previous year so assume no
-        if prev.did_saturate {
-            return false;
-        }
+    /// Return the first leap year after this year.
+    ///
+    /// Every year is checked with [`Self::is_leap`] since the gap between leap years is not fixed.
+    /// If no leap year is found until [`Self::MAX`], it is returned as saturated.
+    pub const fn next_leap(&self) -> DidSaturate<Self> {
+        self.step_to_leap(1)
+    }
+
+    /// Return the last leap year before this year.
+    ///
+    /// Every year is checked with [`Self::is_leap`] since the gap between leap years is not fixed.
+    /// If no leap year is found until [`Self::MIN`], it is returned as saturated.
+    pub const fn prev_leap(&self) -> DidSaturate<Self> {
+        self.step_to_leap(-1)
+    }
 
-        if self.cmp(&Self::NON_LEAP_CORRECTION[0]).is_gt() && prev.result.is_no_leap_correction() {
-            return true;
+    /// Step the year by the given step until it is leap or saturated.
+    const fn step_to_leap(&self, step: IYear) -> DidSaturate<Self> {
+        let mut year = *self;
+        loop {
+            let next = year.add_strict(step);
+            if next.did_saturate {
+                return next;
+            }
+            year = next.result;
+            if year.is_leap() {
+                return DidSaturate::not_saturated(year);
+            }
         }
+    }
 
-        (25 * self.0 as i64 + 11).rem_euclid(33) < 8
+    /// Return an iterator over the leap years from `start` to `end` (both inclusive).
+    ///
+    /// See [`Self::is_leap`] for how the years are checked.
+    pub fn leap_years_between(start: Self, end: Self) -> impl Iterator<Item = Self> {
+        (start.0..=end.0)
+            .filter(|&year| year != 0)
+            .map(Self)
+            .filter(Self::is_leap)
     }
 
     /// Return the number of the maximum consecutive day of the year (365 or 366 for leaps).
@@ -302,6 +524,31 @@ impl Year {
         }
     }
 
+    /// Return the ordinal of the first day of each month of this year in order.
+    ///
+    /// This is the same for every year since the leap day is the last day of the year and does
+    /// not change the start of any month.
+    pub const fn month_start_ordinals(&self) -> [Ordinal; 12] {
+        let mut ordinals = [Ordinal::MIN; 12];
+        let mut i = 0;
+        while i < ordinals.len() {
+            let month = Month(Month::MIN.0 + i as UMonth);
+            ordinals[i] = month.to_ordinal_assume_zero().add_strict(1).result;
+            i += 1;
+        }
+        ordinals
+    }
+
+    /// Return the date at the given progress through this year in per mille (leap correct).
+    ///
+    /// 0 is the first day of the year and 1000 is the last. Greater values are clamped to 1000.
+    pub const fn date_at_fraction(&self, permille: u16) -> Date {
+        let permille = if permille > 1000 { 1000 } else { permille };
+        let last_index = (self.max_ordinal().get() - Ordinal::MIN.get()) as u32;
+        let index = (last_index * permille as u32 / 1000) as UOrdinal; // at most the last index
+        Date::new(*self, Ordinal(Ordinal::MIN.get() + index))
+    }
+
     /// Return the owned types of this value.
     pub const fn get(&self) -> IYear {
         self.0
@@ -324,3 +571,10 @@ impl Ord for Year {
         Self::cmp(self, other)
     }
 }
+
+impl Default for Year {
+    /// Return the year of Unix Epoch ([`Self::EPOCH`]) like [`Date::default`].
+    fn default() -> Self {
+        Self::EPOCH
+    }
+}
@@ -0,0 +1,103 @@
+//! Holds the [`DateRange`] iterator over consecutive days.
+
+use core::iter::FusedIterator;
+
+use crate::Date;
+
+/// An iterator over each day from a start date (inclusive) to an end date (exclusive).
+///
+/// See [`Date::range`]. This stops if a date cannot be reached without saturating instead of
+/// repeating the saturated value forever.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DateRange {
+    /// The next date returned from the front.
+    start: Date,
+    /// The date after the next date returned from the back.
+    end: Date,
+}
+
+impl DateRange {
+    /// Create a new range of days from `start` (inclusive) to `end` (exclusive).
+    pub const fn new(start: Date, end: Date) -> Self {
+        Self { start, end }
+    }
+
+    /// Is there no more days left in this range.
+    pub const fn is_empty(&self) -> bool {
+        self.start.cmp(&self.end).is_ge()
+    }
+
+    /// Make this range empty.
+    const fn finish(&mut self) {
+        self.start = Date::new(self.end.year, self.end.ordinal);
+    }
+}
+
+// Not in the main `impl` block since iterators are not exported to FFI.
+impl Date {
+    /// Return an iterator over each day from this date (inclusive) to the given one (exclusive).
+    pub const fn range(self, end: Self) -> DateRange {
+        DateRange::new(self, end)
+    }
+
+    /// Return an iterator over the first day of each month from the month of this date to the
+    /// month of the given one (both inclusive).
+    ///
+    /// This stops if the next month cannot be reached without saturating.
+    pub fn iter_months(self, end: Self) -> impl Iterator<Item = Date> {
+        let mut next = Some(self.first_day_of_month());
+        core::iter::from_fn(move || {
+            let current = next.take().filter(|v| *v <= end)?;
+            let step = current.clone().add_months_strict(1);
+            if !step.did_saturate {
+                next = Some(step.result);
+            }
+            Some(current)
+        })
+    }
+}
+
+impl Iterator for DateRange {
+    type Item = Date;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.is_empty() {
+            return None;
+        }
+        let next = self.start.clone().add_days_strict(1);
+        let current = core::mem::replace(&mut self.start, next.result);
+        if next.did_saturate {
+            self.finish();
+        }
+        Some(current)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.is_empty() {
+            return (0, Some(0));
+        }
+        let days = self.end.diff_as_days_strict(self.start.clone());
+        if days.did_saturate {
+            (days.result as usize, None)
+        } else {
+            (days.result as usize, Some(days.result as usize))
+        }
+    }
+}
+
+impl DoubleEndedIterator for DateRange {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.is_empty() {
+            return None;
+        }
+        let previous = self.end.clone().add_days_strict(-1);
+        if previous.did_saturate {
+            self.finish();
+            return None;
+        }
+        self.end = previous.result;
+        Some(self.end.clone())
+    }
+}
+
+impl FusedIterator for DateRange {}
@@ -0,0 +1,27 @@
+//! Holds the seasons of the year and their utilities.
+
+use crate::Month;
+
+/// A season of the Jalali year, each made of three consecutive months.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(u8)]
+pub enum Season {
+    /// Bahar (spring), Farvardin to Khordad.
+    Spring = 0,
+    /// Tabestan (summer), Tir to Shahrivar.
+    Summer = 1,
+    /// Paeez (autumn), Mehr to Azar.
+    Autumn = 2,
+    /// Zemestan (winter), Dey to Esfand.
+    Winter = 3,
+}
+
+impl Season {
+    /// All the seasons in order, starting from the season of [`Month::MIN`].
+    pub const SEASONS: [Self; 4] = [Self::Spring, Self::Summer, Self::Autumn, Self::Winter];
+
+    /// Return the season which the given month is in.
+    pub const fn from_month(month: Month) -> Self {
+        Self::SEASONS[((month.get() - Month::MIN.get()) / 3) as usize]
+    }
+}
@@ -0,0 +1,93 @@
+//! Holds the `serde` implementations of the types which are not simple numbers.
+//!
+//! The numbers ([`crate::Year`], [`crate::Month`] and [`crate::Ordinal`]) are serialized as their
+//! inner value. All the values are saturated using their constructors when deserialized.
+
+use core::fmt;
+
+use ::serde::{Deserialize, Deserializer, Serialize, Serializer, de};
+
+use crate::{Date, IYear, MonthDay, UMonth, UMonthDay};
+
+/// The fields of [`MonthDay`] before being saturated.
+#[derive(Serialize, Deserialize)]
+struct RawMonthDay {
+    month: UMonth,
+    day: UMonthDay,
+}
+
+/// Serialize as `{"month": .., "day": ..}`.
+impl Serialize for MonthDay {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        RawMonthDay {
+            month: self.month.get(),
+            day: self.day,
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for MonthDay {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let RawMonthDay { month, day } = RawMonthDay::deserialize(deserializer)?;
+        Ok(Self::new(month.into(), day))
+    }
+}
+
+/// Serialize as a zero-padded `"year/month/day"` string (see [`crate::Padded`]).
+///
+/// See [`ymd`] to serialize as `{"year": .., "month": .., "day": ..}` instead.
+impl Serialize for Date {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(&self.padded())
+    }
+}
+
+/// Deserialize from a string accepted by `FromStr` of [`Date`].
+impl<'de> Deserialize<'de> for Date {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        /// Parse the borrowed or owned strings without allocating.
+        struct DateVisitor;
+
+        impl de::Visitor<'_> for DateVisitor {
+            type Value = Date;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                write!(f, "a date as \"year/month/day\"")
+            }
+
+            fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+                v.parse().map_err(E::custom)
+            }
+        }
+
+        deserializer.deserialize_str(DateVisitor)
+    }
+}
+
+/// Serialize and deserialize a [`Date`] as `{"year": .., "month": .., "day": ..}`.
+///
+/// Use with `#[serde(with = "jelal::serde::ymd")]`.
+pub mod ymd {
+    use super::*;
+
+    /// The fields of [`Date`] before being saturated.
+    #[derive(Serialize, Deserialize)]
+    struct RawYmd {
+        year: IYear,
+        month: UMonth,
+        day: UMonthDay,
+    }
+
+    /// Serialize the given date as `{"year": .., "month": .., "day": ..}`.
+    pub fn serialize<S: Serializer>(date: &Date, serializer: S) -> Result<S::Ok, S::Error> {
+        let (year, month, day) = date.clone().into();
+        RawYmd { year, month, day }.serialize(serializer)
+    }
+
+    /// Deserialize a date from `{"year": .., "month": .., "day": ..}`.
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Date, D::Error> {
+        let RawYmd { year, month, day } = RawYmd::deserialize(deserializer)?;
+        Ok(Date::from((year, month, day)))
+    }
+}
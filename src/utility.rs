@@ -1,5 +1,7 @@
 //! Miscellaneous generic utilities.
 
+use crate::error::Saturated;
+
 /// Did the results of the last operation (`+` for example), saturate or not.
 ///
 /// This is supposed to behave like `Option<T>` of `checked_*` operations but more concrete and
@@ -24,6 +26,52 @@ impl<T> DidSaturate<T> {
         Self::new(false, result)
     }
 
+    /// Return the result only if it did saturate.
+    ///
+    /// This is the opposite of the conversion to `Option` which only keeps the exact results.
+    pub fn into_saturated(self) -> Option<T> {
+        match self.did_saturate {
+            true => Some(self.result),
+            false => None,
+        }
+    }
+
+    /// Return the result only if it did not saturate (same as the conversion to `Option`).
+    pub fn ok(self) -> Option<T> {
+        self.into()
+    }
+
+    /// Return the result if it did not saturate, otherwise the saturated value as an error.
+    ///
+    /// This is to propagate saturation with `?` (e.g. `date.add_days_strict(n).into_result()?`).
+    pub fn into_result(self) -> Result<T, Saturated<T>> {
+        match self.did_saturate {
+            true => Err(Saturated(self.result)),
+            false => Ok(self.result),
+        }
+    }
+
+    /// Apply a function to the result keeping [`Self::did_saturate`].
+    ///
+    /// This cannot be used in const-context as closures can not be called there.
+    pub fn map<U>(self, f: impl FnOnce(T) -> U) -> DidSaturate<U> {
+        DidSaturate::new(self.did_saturate, f(self.result))
+    }
+
+    /// Chain another saturating operation on the result, saturated if either did.
+    pub fn and_then<U>(self, f: impl FnOnce(T) -> DidSaturate<U>) -> DidSaturate<U> {
+        let v = f(self.result);
+        DidSaturate::new(self.did_saturate || v.did_saturate, v.result)
+    }
+
+    /// Return the result if it did not saturate, otherwise the given default.
+    pub fn unwrap_or(self, default: T) -> T {
+        match self.did_saturate {
+            true => default,
+            false => self.result,
+        }
+    }
+
     /// A shorthand for creation.
     //
     // Sometimes the results are passed and did_saturate may use it, having did_saturate at the
@@ -36,11 +84,57 @@ impl<T> DidSaturate<T> {
     }
 }
 
+/// Convert the days since Unix Epoch to a proleptic Gregorian year, month and day.
+///
+/// This is the `civil_from_days` algorithm of Howard Hinnant, see
+/// <https://howardhinnant.github.io/date_algorithms.html#civil_from_days>.
+pub(crate) const fn civil_from_days(days: i64) -> (i64, u8, u8) {
+    let z = days + 719468;
+    let era = z.div_euclid(146097);
+    let doe = z.rem_euclid(146097); // [0, 146096]
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146096) / 365; // [0, 399]
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100); // [0, 365]
+    let mp = (5 * doy + 2) / 153; // [0, 11]
+    let day = (doy - (153 * mp + 2) / 5 + 1) as u8;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 } as u8;
+    let year = yoe + era * 400;
+    (if month <= 2 { year + 1 } else { year }, month, day)
+}
+
+/// Convert a proleptic Gregorian year, month and day to the days since Unix Epoch.
+///
+/// This is the `days_from_civil` algorithm of Howard Hinnant, see
+/// <https://howardhinnant.github.io/date_algorithms.html#days_from_civil>.
+pub(crate) const fn days_from_civil(year: i64, month: u8, day: u8) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let yoe = year.rem_euclid(400); // [0, 399]
+    let mp = if month > 2 { month - 3 } else { month + 9 } as i64; // [0, 11]
+    let doy = (153 * mp + 2) / 5 + day as i64 - 1; // [0, 365]
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy; // [0, 146096]
+    era * 146097 + doe - 719468
+}
+
+/// Convert a signed value to an unsigned one limited to `max` (negatives are 0).
+#[cfg(feature = "c")]
+pub(crate) const fn saturate_unsigned(value: i32, max: u16) -> u16 {
+    if value < 0 {
+        0
+    } else if value > max as i32 {
+        max
+    } else {
+        value as u16
+    }
+}
+
+/// Return the result only if it did not saturate, like `checked_*` operations.
+///
+/// See [`DidSaturate::into_saturated`] for the opposite.
 impl<T> From<DidSaturate<T>> for Option<T> {
     fn from(value: DidSaturate<T>) -> Self {
         match value.did_saturate {
-            true => Some(value.result),
-            false => None,
+            true => None,
+            false => Some(value.result),
         }
     }
 }
@@ -0,0 +1,93 @@
+//! Holds the days of the week and their utilities.
+
+use crate::IDayDiff;
+
+/// A day of the week, ordered from the first day of the Jalali week.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(u8)]
+pub enum Weekday {
+    /// Shanbe, the first day of the Jalali week.
+    Saturday = 0,
+    /// Yekshanbe.
+    Sunday = 1,
+    /// Doshanbe.
+    Monday = 2,
+    /// Seshanbe.
+    Tuesday = 3,
+    /// Chaharshanbe.
+    Wednesday = 4,
+    /// Panjshanbe.
+    Thursday = 5,
+    /// Jomeh, the last day of the Jalali week.
+    Friday = 6,
+}
+
+/// The day which a week is assumed to start on (see [`Weekday::MIN`] for the Jalali default).
+pub type WeekStart = Weekday;
+
+impl Weekday {
+    /// Unix Epoch in this format (Gregorian 1st of January 1970 was a Thursday).
+    pub const EPOCH: Self = Self::Thursday;
+
+    /// The first day of the Jalali week; Saturday (Shanbe).
+    pub const MIN: Self = Self::Saturday;
+
+    /// The last day of the Jalali week; Friday (Jomeh).
+    pub const MAX: Self = Self::Friday;
+
+    /// All the days of the week in order, starting from [`Self::MIN`].
+    pub const WEEKDAYS: [Self; 7] = [
+        Self::Saturday,
+        Self::Sunday,
+        Self::Monday,
+        Self::Tuesday,
+        Self::Wednesday,
+        Self::Thursday,
+        Self::Friday,
+    ];
+
+    /// Return the weekday which is the given number of days away from this one.
+    pub const fn add_days(self, days: IDayDiff) -> Self {
+        // the remainder is small so it will not overflow when added
+        Self::WEEKDAYS[(self as IDayDiff + days % 7).rem_euclid(7) as usize]
+    }
+
+    /// Return the Persian name of this day of the week (e.g. "شنبه" for [`Self::Saturday`]).
+    pub const fn name(&self) -> &'static str {
+        const NAMES: [&str; 7] = [
+            "شنبه",
+            "یکشنبه",
+            "دوشنبه",
+            "سه‌شنبه",
+            "چهارشنبه",
+            "پنجشنبه",
+            "جمعه",
+        ];
+        NAMES[*self as usize]
+    }
+
+    /// Return the romanized Persian name of this day of the week (e.g. "Shanbe" for
+    /// [`Self::Saturday`]).
+    pub const fn name_latin(&self) -> &'static str {
+        const NAMES: [&str; 7] = [
+            "Shanbe",
+            "Yekshanbe",
+            "Doshanbe",
+            "Seshanbe",
+            "Chaharshanbe",
+            "Panjshanbe",
+            "Jomeh",
+        ];
+        NAMES[*self as usize]
+    }
+
+    /// Return the day of the week as in `tm_wday` of C's `struct tm` (0 to 6, from Sunday).
+    pub const fn to_c_wday(&self) -> u8 {
+        self.days_since(Self::Sunday)
+    }
+
+    /// Return how many days are passed since the given start of the week (0 to 6).
+    pub const fn days_since(&self, start: WeekStart) -> u8 {
+        (*self as u8 + 7 - start as u8) % 7
+    }
+}